@@ -6,12 +6,10 @@ use std::fs::OpenOptions;
 
 fn main() -> Result<()> {
     let file = OpenOptions::new().read(true).open("input")?;
-    let mut map = Map::read(file)?;
-
-    map.sanitize();
+    let map = Map::read(file)?;
 
     println!("part one: {}", map.path().count() / 2);
-    println!("part two: {}", map.enclosed_tiles_count()?);
+    println!("part two: {}", map.enclosed_tiles_count_pick());
 
     Ok(())
 }
@@ -1,5 +1,6 @@
 use anyhow::{anyhow, bail, Error, Result};
 use itertools::Itertools;
+use map2d::geometry::Polygon;
 use std::io::{BufRead, BufReader, Read};
 
 use Direction::*;
@@ -17,9 +18,6 @@ pub struct Map {
     start: Position,
     max_x: usize,
     max_y: usize,
-    height: usize,
-    width: usize,
-    sanitized: bool,
 }
 
 impl Map {
@@ -72,9 +70,6 @@ impl Map {
             start,
             max_x,
             max_y,
-            height,
-            width,
-            sanitized: false,
         };
 
         let mut connected_directions = Vec::with_capacity(2);
@@ -134,15 +129,12 @@ impl Map {
         self.inner[position.y][position.x]
     }
 
-    pub fn enclosed_tiles_count(&self) -> Result<usize> {
+    #[cfg(test)]
+    fn enclosed_tiles_count(&self) -> usize {
         const INTERSECT_IGNORE_PIPES: [Pipe; 3] =
             [Pipe::NorthEast, Pipe::EastWest, Pipe::NorthWest];
 
-        if !self.sanitized {
-            bail!("Map must be sanitized before calling this function");
-        }
-
-        Ok(self.inner.iter().fold(0, |mut count, row| {
+        self.sanitized().iter().fold(0, |mut count, row| {
             let _ = row.iter().copied().fold(0, |mut intersect_count, tile| {
                 match tile {
                     Tile::Pipe(pipe) if !INTERSECT_IGNORE_PIPES.contains(&pipe) => {
@@ -156,33 +148,29 @@ impl Map {
                 intersect_count
             });
             count
-        }))
+        })
     }
 
-    pub fn path(&self) -> Path {
-        Path::new(self)
+    pub fn enclosed_tiles_count_pick(&self) -> usize {
+        let polygon: Polygon = self
+            .path()
+            .map(|position| (position.x as i64, position.y as i64))
+            .collect();
+        polygon.interior_points() as usize
     }
 
-    pub fn sanitize(&mut self) {
-        if self.sanitized {
-            return;
-        }
-
-        let mut empty_row = Vec::with_capacity(self.width);
-        empty_row.extend(std::iter::repeat(Tile::Ground).take(self.width));
+    pub fn path(&self) -> Path<'_> {
+        Path::new(self)
+    }
 
-        let mut map = Vec::with_capacity(self.height);
-        map.extend(std::iter::repeat(empty_row.clone()).take(self.height));
+    #[cfg(test)]
+    fn sanitized(&self) -> Vec<Vec<Tile>> {
+        let mut map = vec![vec![Tile::Ground; self.max_x + 1]; self.max_y + 1];
 
         for position in self.path() {
             map[position.y][position.x] = self.get(position);
         }
-
-        *self = Map {
-            inner: map,
-            sanitized: true,
-            ..*self
-        }
+        map
     }
 }
 
@@ -351,3 +339,32 @@ impl TryFrom<char> for Tile {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    fn example_literal() -> &'static str {
+        indoc! {"
+            FF7FSF7F7F7F7F7F---7
+            L|LJ||||||||||||F--J
+            FL-7LJLJ||||||LJL-77
+            F--JF--7||LJLJ7F7FJ-
+            L---JF-JLJ.||-FJLJJ7
+            |F|F-JF---7F7-L7L|7|
+            |FFJF7L7F-JF7|JL---7
+            7-L-JL7||F7|L7F-7F7|
+            L.L7LFJ|||||FJL7||LJ
+            L7JLJL-JLJLJL--JLJ.L
+        "}
+    }
+
+    #[test]
+    fn enclosed_tiles() -> Result<()> {
+        let map = Map::read(example_literal().as_bytes())?;
+        assert_eq!(map.enclosed_tiles_count_pick(), 10);
+        assert_eq!(map.enclosed_tiles_count(), 10);
+        Ok(())
+    }
+}
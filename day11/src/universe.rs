@@ -1,5 +1,6 @@
 use anyhow::{bail, Error, Result};
 use itertools::Itertools;
+use map2d::PrefixSum;
 use std::fmt::{self, Write};
 use std::io::{BufRead, BufReader, Read};
 
@@ -8,6 +9,8 @@ use Cell::*;
 #[derive(Debug, PartialEq, Eq)]
 pub struct Universe {
     inner: Vec<Vec<Cell>>,
+    void_columns: PrefixSum,
+    void_rows: PrefixSum,
 }
 
 impl Universe {
@@ -20,34 +23,31 @@ impl Universe {
                     .map(Cell::try_from)
                     .collect::<Result<_>>()
             })
-            .collect::<Result<_>>()?;
+            .collect::<Result<Vec<Vec<Cell>>>>()?;
 
-        let mut universe = Self { inner };
-
-        universe.expand();
-
-        Ok(universe)
+        let void_rows = inner
+            .iter()
+            .map(|row| row.iter().copied().all(Cell::is_space) as i64)
+            .collect();
+        let width = inner.first().map_or(0, Vec::len);
+        let void_columns = (0..width)
+            .map(|x| inner.iter().all(|row| row[x].is_space()) as i64)
+            .collect();
+
+        Ok(Self {
+            inner,
+            void_columns,
+            void_rows,
+        })
     }
 
     fn distance(&self, a: Position, b: Position, void_size: usize) -> usize {
-        let mut distance = 0;
-        for x in a.x.min(b.x)..a.x.max(b.x) {
-            match self.get(Position { x, y: a.y }).is_horizontal_void() {
-                true => distance += void_size,
-                false => distance += 1,
-            }
-        }
-        for y in a.y.min(b.y)..a.y.max(b.y) {
-            match self.get(Position { x: a.x, y }).is_vertical_void() {
-                true => distance += void_size,
-                false => distance += 1,
-            }
-        }
-        distance
-    }
+        let columns = a.x.min(b.x)..a.x.max(b.x);
+        let rows = a.y.min(b.y)..a.y.max(b.y);
+        let voids =
+            (self.void_columns.sum(columns.clone()) + self.void_rows.sum(rows.clone())) as usize;
 
-    fn get(&self, position: Position) -> Cell {
-        self.inner[position.y][position.x]
+        voids * void_size + (columns.len() + rows.len() - voids)
     }
 
     pub fn distances(&self, void_size: usize) -> impl Iterator<Item = usize> + '_ {
@@ -56,36 +56,6 @@ impl Universe {
             .map(move |pair| self.distance(pair[0], pair[1], void_size))
     }
 
-    fn expand(&mut self) {
-        self.inner
-            .iter_mut()
-            .filter(|row| row.iter().all(|cell| *cell == Cell::Space))
-            .for_each(|row| row.iter_mut().for_each(|cell| *cell = Cell::VerticalVoid));
-
-        for column_idx in 0..self.inner[0].len() {
-            if self.column_cells(column_idx).all(Cell::is_space) {
-                self.column_cells_mut(column_idx).for_each(|cell| {
-                    *cell = match cell {
-                        Cell::VerticalVoid => Cell::Void,
-                        _ => Cell::HorizontalVoid,
-                    }
-                });
-            }
-        }
-    }
-
-    fn column_cells_mut(&mut self, index: usize) -> impl Iterator<Item = &mut Cell> + '_ {
-        self.inner
-            .iter_mut()
-            .map(move |row| row.get_mut(index).unwrap())
-    }
-
-    fn column_cells(&self, index: usize) -> impl Iterator<Item = Cell> + '_ {
-        self.inner
-            .iter()
-            .map(move |row| row.get(index).copied().unwrap())
-    }
-
     fn galaxies(&self) -> impl Iterator<Item = Position> + '_ {
         self.inner.iter().enumerate().flat_map(|(y, row)| {
             row.iter()
@@ -121,20 +91,11 @@ struct Position {
 enum Cell {
     Space,
     Galaxy,
-    HorizontalVoid,
-    VerticalVoid,
-    Void,
 }
 
 impl Cell {
-    fn is_vertical_void(self) -> bool {
-        self == VerticalVoid || self == Void
-    }
-    fn is_horizontal_void(self) -> bool {
-        self == HorizontalVoid || self == Void
-    }
     fn is_space(self) -> bool {
-        !matches!(self, Cell::Galaxy)
+        self == Cell::Space
     }
 }
 
@@ -155,7 +116,6 @@ impl From<Cell> for char {
         match cell {
             Space => '.',
             Galaxy => '#',
-            _ => '+',
         }
     }
 }
@@ -173,8 +133,7 @@ mod tests {
 
     #[test]
     fn distances() -> Result<()> {
-        let mut universe = Universe::from_reader(OpenOptions::new().read(true).open("test")?)?;
-        universe.expand();
+        let universe = Universe::from_reader(OpenOptions::new().read(true).open("test")?)?;
 
         assert_eq!(
             universe.distance(Position { x: 3, y: 0 }, Position { x: 7, y: 8 }, 2),
@@ -187,6 +146,10 @@ mod tests {
 
         assert_eq!(universe.distances(10).sum::<usize>(), 1030);
         assert_eq!(universe.distances(100).sum::<usize>(), 8410);
+        assert_eq!(
+            universe.distance(Position { x: 3, y: 0 }, Position { x: 7, y: 8 }, 0),
+            9
+        );
 
         Ok(())
     }
@@ -3,7 +3,7 @@ pub use Node::*;
 use anyhow::{bail, Error, Result};
 use std::fmt::{self, Write};
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub enum Node {
     Space,
     RoundedRock,
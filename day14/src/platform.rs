@@ -4,7 +4,7 @@ use std::fmt::{self, Write};
 use std::io::{BufRead, BufReader, Read};
 use std::ops::{self, Range};
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct Platform {
     inner: Box<[PlatformRow]>,
     width: usize,
@@ -184,14 +184,6 @@ impl Platform {
         self.tilt_north().tilt_west().tilt_south().tilt_east()
     }
 
-    pub fn spin_n(self, n: usize) -> Self {
-        let mut pattern = self;
-        for _ in 0..n {
-            pattern = pattern.spin();
-        }
-        pattern
-    }
-
     fn columns(&self) -> Columns<'_> {
         Columns::new(self)
     }
@@ -317,7 +309,7 @@ impl fmt::Display for Platform {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct PlatformRow {
     inner: Box<[Node]>,
 }
@@ -515,6 +507,13 @@ mod tests {
         )
     }
 
+    #[test]
+    fn spin_cycle_load() {
+        let platform =
+            map2d::cycle::fast_forward(example_platform(), |p| p.clone().spin(), 1_000_000_000);
+        assert_eq!(platform.load(), 64);
+    }
+
     #[test]
     fn spin() {
         let pattern = example_platform();
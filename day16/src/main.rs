@@ -1,7 +1,8 @@
 use anyhow::Result;
 use macros::char_enum;
-use map2d::Map2D;
-use std::{fmt::Write, fs::OpenOptions};
+use map2d::parallel::prelude::*;
+use map2d::{BitMap2D, Map2D};
+use std::fs::OpenOptions;
 use Direction::*;
 
 const ITERATIONS_MAX: usize = 100_000;
@@ -75,20 +76,17 @@ trait LightMap {
     fn energized_count(&self) -> usize;
 }
 
-impl LightMap for Map2D<Value> {
+impl LightMap for [BitMap2D; 4] {
     fn is_set(&self, x: usize, y: usize, direction: Direction) -> bool {
-        self[y][x][usize::from(direction)]
+        self[usize::from(direction)].test(x, y)
     }
     fn set(&mut self, x: usize, y: usize, direction: Direction) {
-        self[y][x][usize::from(direction)] = true;
+        self[usize::from(direction)].set(x, y);
     }
     fn energized_count(&self) -> usize {
-        self.rows().fold(0, |count, row| {
-            row.iter()
-                .filter(|values| values.iter().copied().any(std::convert::identity))
-                .count()
-                + count
-        })
+        let mut energized = self[0].clone();
+        self[1..].iter().for_each(|map| energized |= map);
+        energized.count_ones()
     }
 }
 
@@ -99,36 +97,9 @@ struct Beam {
     direction: Direction,
 }
 
-#[derive(Debug, Default, Clone, Copy)]
-struct Value([bool; 4]);
-
-impl std::fmt::Display for Value {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if self.iter().copied().any(std::convert::identity) {
-            f.write_char('#')?;
-        } else {
-            f.write_char('.')?;
-        }
-        Ok(())
-    }
-}
-
-impl std::ops::Deref for Value {
-    type Target = [bool; 4];
-
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
-}
-
-impl std::ops::DerefMut for Value {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
-    }
-}
-
 fn run(grid: &Grid, initial_beam: Beam) -> Result<usize> {
-    let mut light_map: Map2D<Value> = Map2D::new(grid.height(), grid.width());
+    let mut light_map: [BitMap2D; 4] =
+        std::array::from_fn(|_| BitMap2D::new(grid.height(), grid.width()));
     let mut beams = vec![initial_beam];
 
     for _ in 0..ITERATIONS_MAX {
@@ -159,7 +130,7 @@ fn run(grid: &Grid, initial_beam: Beam) -> Result<usize> {
             }
             true
         });
-        beams.extend(new_beams.into_iter());
+        beams.extend(new_beams);
         if beams.is_empty() {
             break;
         }
@@ -216,14 +187,10 @@ fn part_two(grid: &Grid) -> Result<usize> {
         ]
     }));
 
-    let mut max = 0;
-
-    for beam in initial_beams {
-        let value = run(grid, beam)?;
-        max = value.max(max);
-    }
-
-    Ok(max)
+    initial_beams
+        .into_par_iter()
+        .map(|beam| run(grid, beam))
+        .try_reduce(|| 0, |a, b| Ok(a.max(b)))
 }
 
 fn main() -> Result<()> {
@@ -1,63 +1,207 @@
+extern crate self as macros;
+
+pub use macros_derive::CharEnum;
+
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum CharEnumError {
+    InvalidCharacter {
+        character: char,
+        name: &'static str,
+        allowed: &'static [char],
+    },
+    InvalidLength {
+        input: String,
+        name: &'static str,
+        allowed: &'static [char],
+    },
+}
+
+impl fmt::Display for CharEnumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (allowed, expected) = match self {
+            CharEnumError::InvalidCharacter {
+                character,
+                name,
+                allowed,
+            } => {
+                write!(f, "Cannot convert character '{character}' to a {name}")?;
+                (allowed, "one of")
+            }
+            CharEnumError::InvalidLength {
+                input,
+                name,
+                allowed,
+            } => {
+                write!(f, "Cannot convert '{input}' to a {name}")?;
+                (allowed, "a single character out of")
+            }
+        };
+
+        write!(f, ", expected {expected} ")?;
+        for (i, character) in allowed.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "'{character}'")?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for CharEnumError {}
+
 #[macro_export]
 macro_rules! char_enum {
     {
-        $(#[derive($($x:ident),+)])?
+        $(#[$meta:meta])*
         $visibility:vis $name:ident {
-            $($variant:ident => $character:literal$(,)?)+
+            $($(#[$variant_meta:meta])* $variant:ident => $character:literal$(,)?)+
         }
     } => {
-        $(#[derive($($x),+)])?
+        $(#[$meta])*
+        #[derive($crate::CharEnum)]
         $visibility enum $name {
-            $($variant),+
+            $($(#[$variant_meta])* #[ch($character)] $variant),+
         }
 
-        impl TryFrom<char> for $name {
-            type Error = anyhow::Error;
-
-            fn try_from(character: char) -> anyhow::Result<Self> {
-                Ok(match character {
-                    $($character => $name::$variant),+,
-                    _ => anyhow::bail!("Cannot convert character '{character}' to a {}", stringify!($name)),
-                })
-            }
-        }
+        $crate::__char_enum_serde!($name);
+    };
+}
 
-        impl From<$name> for char {
-            fn from(value: $name) -> char {
-                match value {
-                    $($name::$variant => $character),+,
-                }
-            }
-        }
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub use serde as __serde;
 
-        impl From<&$name> for char {
-            fn from(value: &$name) -> char {
-                match value {
-                    $(&$name::$variant => $character),+,
-                }
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __char_enum_serde {
+    ($name:ident) => {
+        impl $crate::__serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: $crate::__serde::Serializer,
+            {
+                serializer.serialize_char(self.into())
             }
         }
 
-        impl std::fmt::Display for $name {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                std::fmt::Write::write_char(f, self.into())
+        impl<'de> $crate::__serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+            where
+                D: $crate::__serde::Deserializer<'de>,
+            {
+                let character = <char as $crate::__serde::Deserialize>::deserialize(deserializer)?;
+                $name::try_from(character).map_err($crate::__serde::de::Error::custom)
             }
         }
     };
 }
 
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __char_enum_serde {
+    ($name:ident) => {};
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::CharEnum;
+
     char_enum! {
+        /// Nodes are written as their digit.
         #[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
         pub Node {
+            /// The first node.
             First => '1',
             Second => '2',
         }
     }
 
+    #[derive(Debug, Clone, PartialEq, CharEnum)]
+    enum Tile<T> {
+        #[ch('.')]
+        Empty,
+        #[ch('#')]
+        Wall(T),
+        #[ch('S')]
+        Start { visited: bool },
+    }
+
     #[test]
     fn display() {
         assert_eq!(Node::First.to_string(), "1");
+        assert_eq!(Tile::Wall(3u8).to_string(), "#");
+    }
+
+    #[derive(Debug)]
+    struct NoDefault;
+
+    #[test]
+    fn unbounded_generic() {
+        let wall = Tile::Wall(NoDefault);
+        assert_eq!(wall.to_string(), "#");
+        assert_eq!(char::from(&wall), '#');
+        assert_eq!(Tile::<NoDefault>::CHARACTERS.len(), 3);
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(Node::try_from('2').unwrap(), Node::Second);
+        assert_eq!(char::from(Node::First), '1');
+        assert_eq!("S".parse::<Tile<u8>>(), Ok(Tile::Start { visited: false }));
+        assert_eq!(Tile::<u8>::try_from('#'), Ok(Tile::Wall(0)));
+        assert_eq!(Tile::<u8>::CHARACTERS, &['.', '#', 'S']);
+    }
+
+    #[test]
+    fn errors() {
+        let error = Node::try_from('x').unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Cannot convert character 'x' to a Node, expected one of '1', '2'"
+        );
+
+        let error = "..".parse::<Tile<u8>>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Cannot convert '..' to a Tile, expected a single character out of '.', '#', 'S'"
+        );
+        assert!("".parse::<Node>().is_err());
+        assert!(anyhow::Error::from(error)
+            .to_string()
+            .starts_with("Cannot convert"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        assert_eq!(serde_json::to_string(&Node::Second).unwrap(), r#""2""#);
+        assert_eq!(serde_json::from_str::<Node>(r#""1""#).unwrap(), Node::First);
+        assert!(serde_json::from_str::<Node>(r#""3""#).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    mod result_alias {
+        #[allow(dead_code)]
+        type Result<T> = std::result::Result<T, ()>;
+
+        char_enum! {
+            #[derive(Debug, PartialEq)]
+            Bit {
+                Zero => '0',
+                One => '1',
+            }
+        }
+
+        #[test]
+        fn serde_with_result_alias() {
+            assert_eq!(serde_json::from_str::<Bit>(r#""1""#).unwrap(), Bit::One);
+            assert_eq!(serde_json::to_string(&Bit::Zero).unwrap(), r#""0""#);
+        }
     }
 }
@@ -0,0 +1,159 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Error, Fields, LitChar, Result};
+
+#[proc_macro_derive(CharEnum, attributes(ch))]
+pub fn derive_char_enum(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> Result<TokenStream2> {
+    let Data::Enum(data) = &input.data else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "CharEnum can only be derived for enums",
+        ));
+    };
+    if data.variants.is_empty() {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "CharEnum requires at least one variant",
+        ));
+    }
+
+    let mut characters: Vec<LitChar> = vec![];
+    let mut constructors = vec![];
+    let mut patterns = vec![];
+    let mut field_types = vec![];
+
+    let name = &input.ident;
+    for variant in data.variants.iter() {
+        let mut attributes = variant.attrs.iter().filter(|a| a.path().is_ident("ch"));
+        let Some(attribute) = attributes.next() else {
+            return Err(Error::new_spanned(
+                variant,
+                "missing #[ch('x')] attribute on CharEnum variant",
+            ));
+        };
+        if let Some(duplicate) = attributes.next() {
+            return Err(Error::new_spanned(
+                duplicate,
+                "duplicate #[ch] attribute on CharEnum variant",
+            ));
+        }
+
+        let character: LitChar = attribute.parse_args()?;
+        if let Some(previous) = characters.iter().find(|c| c.value() == character.value()) {
+            return Err(Error::new_spanned(
+                &character,
+                format!(
+                    "character {:?} is already used by another variant",
+                    previous.value()
+                ),
+            ));
+        }
+
+        let ident = &variant.ident;
+        let (constructor, pattern) = match &variant.fields {
+            Fields::Unit => (quote!(Self::#ident), quote!(#name::#ident)),
+            Fields::Unnamed(fields) => {
+                let defaults = fields
+                    .unnamed
+                    .iter()
+                    .map(|_| quote!(::core::default::Default::default()));
+                (
+                    quote!(Self::#ident(#(#defaults),*)),
+                    quote!(#name::#ident(..)),
+                )
+            }
+            Fields::Named(fields) => {
+                let defaults = fields.named.iter().map(|field| {
+                    let field = &field.ident;
+                    quote!(#field: ::core::default::Default::default())
+                });
+                (
+                    quote!(Self::#ident { #(#defaults),* }),
+                    quote!(#name::#ident { .. }),
+                )
+            }
+        };
+        field_types.extend(variant.fields.iter().map(|field| &field.ty));
+        constructors.push(constructor);
+        patterns.push(pattern);
+        characters.push(character);
+    }
+
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let mut parse_generics = input.generics.clone();
+    let predicates = &mut parse_generics.make_where_clause().predicates;
+    for ty in field_types {
+        predicates.push(parse_quote!(#ty: ::core::default::Default));
+    }
+    let (_, _, parse_where_clause) = parse_generics.split_for_impl();
+    let type_name = name.to_string();
+
+    Ok(quote! {
+        impl #impl_generics #name #type_generics #where_clause {
+            pub const CHARACTERS: &'static [char] = &[#(#characters),*];
+        }
+
+        impl #impl_generics ::core::convert::TryFrom<char> for #name #type_generics #parse_where_clause {
+            type Error = ::macros::CharEnumError;
+
+            fn try_from(character: char) -> ::core::result::Result<Self, Self::Error> {
+                ::core::result::Result::Ok(match character {
+                    #(#characters => #constructors,)*
+                    _ => {
+                        return ::core::result::Result::Err(::macros::CharEnumError::InvalidCharacter {
+                            character,
+                            name: #type_name,
+                            allowed: Self::CHARACTERS,
+                        })
+                    }
+                })
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<&#name #type_generics> for char #where_clause {
+            fn from(value: &#name #type_generics) -> char {
+                match value {
+                    #(#patterns => #characters,)*
+                }
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<#name #type_generics> for char #where_clause {
+            fn from(value: #name #type_generics) -> char {
+                char::from(&value)
+            }
+        }
+
+        impl #impl_generics ::core::fmt::Display for #name #type_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Write::write_char(f, char::from(self))
+            }
+        }
+
+        impl #impl_generics ::core::str::FromStr for #name #type_generics #parse_where_clause {
+            type Err = ::macros::CharEnumError;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                let mut characters = s.chars();
+                match (characters.next(), characters.next()) {
+                    (::core::option::Option::Some(character), ::core::option::Option::None) => {
+                        Self::try_from(character)
+                    }
+                    _ => ::core::result::Result::Err(::macros::CharEnumError::InvalidLength {
+                        input: s.to_owned(),
+                        name: #type_name,
+                        allowed: Self::CHARACTERS,
+                    }),
+                }
+            }
+        }
+    })
+}
@@ -0,0 +1,194 @@
+use crate::{Bounds, Grid, GridMut, Map2D};
+use std::fmt::{self, Display, Write};
+use std::ops::{Index, IndexMut};
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct ArrayGrid<T, const W: usize, const H: usize> {
+    cells: [[T; W]; H],
+}
+
+impl<const W: usize, const H: usize> ArrayGrid<u8, W, H> {
+    pub const fn from_ascii(literal: &str) -> Self {
+        let bytes = literal.as_bytes();
+        let mut cells = [[0; W]; H];
+        let (mut x, mut y, mut i) = (0, 0, 0);
+
+        while i < bytes.len() {
+            let byte = bytes[i];
+            if byte == b'\n' {
+                assert!(x == W, "row is narrower than the grid width");
+                x = 0;
+                y += 1;
+            } else {
+                assert!(byte.is_ascii(), "grid literal must be ASCII");
+                assert!(y < H, "grid literal has more rows than the grid height");
+                assert!(x < W, "row is wider than the grid width");
+                cells[y][x] = byte;
+                x += 1;
+            }
+            i += 1;
+        }
+        if x > 0 {
+            assert!(x == W, "row is narrower than the grid width");
+            y += 1;
+        }
+        assert!(y == H, "grid literal has fewer rows than the grid height");
+
+        Self { cells }
+    }
+}
+
+impl<T, const W: usize, const H: usize> ArrayGrid<T, W, H> {
+    pub const fn new(cells: [[T; W]; H]) -> Self {
+        Self { cells }
+    }
+    pub fn from_fn<F>(mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        Self::new(std::array::from_fn(|y| std::array::from_fn(|x| f(x, y))))
+    }
+    pub const fn width(&self) -> usize {
+        W
+    }
+    pub const fn height(&self) -> usize {
+        H
+    }
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.cells.get(y)?.get(x)
+    }
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.cells.get_mut(y)?.get_mut(x)
+    }
+    pub fn rows(&self) -> impl Iterator<Item = &[T; W]> {
+        self.cells.iter()
+    }
+    pub fn iter_with_positions(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.rows()
+            .enumerate()
+            .flat_map(|(y, row)| row.iter().enumerate().map(move |(x, cell)| ((x, y), cell)))
+    }
+    pub fn map<U, F>(&self, mut f: F) -> ArrayGrid<U, W, H>
+    where
+        F: FnMut(&T) -> U,
+    {
+        ArrayGrid::from_fn(|x, y| f(&self.cells[y][x]))
+    }
+    pub fn try_map<U, E, F>(&self, mut f: F) -> Result<ArrayGrid<U, W, H>, E>
+    where
+        F: FnMut(&T) -> Result<U, E>,
+    {
+        let mut cells = Vec::with_capacity(W * H);
+        for cell in self.cells.iter().flatten() {
+            cells.push(f(cell)?);
+        }
+
+        let mut cells = cells.into_iter();
+        Ok(ArrayGrid::from_fn(|_, _| cells.next().unwrap()))
+    }
+    pub fn into_inner(self) -> [[T; W]; H] {
+        self.cells
+    }
+}
+
+impl<T: Clone, const W: usize, const H: usize> ArrayGrid<T, W, H> {
+    pub fn to_map(&self) -> Map2D<T> {
+        Map2D::from_fn(H, W, |x, y| self.cells[y][x].clone())
+    }
+}
+
+impl<T: Default, const W: usize, const H: usize> Default for ArrayGrid<T, W, H> {
+    fn default() -> Self {
+        Self::from_fn(|_, _| T::default())
+    }
+}
+
+impl<T, const W: usize, const H: usize> Index<usize> for ArrayGrid<T, W, H> {
+    type Output = [T; W];
+
+    fn index(&self, y: usize) -> &Self::Output {
+        &self.cells[y]
+    }
+}
+
+impl<T, const W: usize, const H: usize> IndexMut<usize> for ArrayGrid<T, W, H> {
+    fn index_mut(&mut self, y: usize) -> &mut Self::Output {
+        &mut self.cells[y]
+    }
+}
+
+impl<T, const W: usize, const H: usize> Grid for ArrayGrid<T, W, H> {
+    type Cell = T;
+
+    fn bounds(&self) -> Bounds {
+        Bounds::new(0, 0, W as i64, H as i64)
+    }
+    fn cell(&self, x: i64, y: i64) -> Option<&T> {
+        self.get(usize::try_from(x).ok()?, usize::try_from(y).ok()?)
+    }
+}
+
+impl<T, const W: usize, const H: usize> GridMut for ArrayGrid<T, W, H> {
+    fn cell_mut(&mut self, x: i64, y: i64) -> Option<&mut T> {
+        self.get_mut(usize::try_from(x).ok()?, usize::try_from(y).ok()?)
+    }
+}
+
+impl<const W: usize, const H: usize> Display for ArrayGrid<u8, W, H> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                f.write_char(*cell as char)?;
+            }
+            f.write_char('\n')?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic;
+
+    const KERNEL: ArrayGrid<u8, 3, 3> = ArrayGrid::from_ascii(".#.\n###\n.#.\n");
+
+    #[test]
+    fn literal() {
+        assert_eq!(KERNEL.to_string(), ".#.\n###\n.#.\n");
+        assert_eq!(KERNEL[1], *b"###");
+        assert_eq!(
+            ArrayGrid::<u8, 2, 1>::from_ascii("ab").get(1, 0),
+            Some(&b'b')
+        );
+
+        let map = KERNEL.map(|cell| *cell as char).to_map();
+        assert_eq!(map.to_string(), KERNEL.to_string());
+
+        assert!(panic::catch_unwind(|| ArrayGrid::<u8, 3, 2>::from_ascii("...\n..\n")).is_err());
+        assert!(panic::catch_unwind(|| ArrayGrid::<u8, 2, 2>::from_ascii("..\n")).is_err());
+    }
+
+    #[test]
+    fn shared_traits() {
+        fn fill<G: GridMut<Cell = u8>>(grid: &mut G) {
+            let positions: Vec<_> = grid.positions().collect();
+            for (x, y) in positions {
+                grid.set(x, y, b'#');
+            }
+        }
+
+        let mut array = KERNEL;
+        let mut map = KERNEL.to_map();
+        fill(&mut array);
+        fill(&mut map);
+
+        assert!(array.cells().all(|(_, cell)| *cell == b'#'));
+        assert_eq!(array.to_map(), map);
+        assert!(!array.set(3, 0, b'.'));
+
+        let digits: Result<ArrayGrid<u32, 3, 3>, _> =
+            KERNEL.try_map(|cell| (*cell as char).to_digit(10).ok_or(*cell));
+        assert_eq!(digits, Err(b'.'));
+    }
+}
@@ -0,0 +1,212 @@
+use crate::cycle;
+use crate::{Direction, Map2D};
+use std::hash::Hash;
+use std::mem;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Neighborhood<'a, T> {
+    map: &'a Map2D<T>,
+    x: usize,
+    y: usize,
+}
+
+impl<'a, T> Neighborhood<'a, T> {
+    pub fn position(&self) -> (usize, usize) {
+        (self.x, self.y)
+    }
+    pub fn cell(&self) -> &'a T {
+        &self.map[self.y][self.x]
+    }
+    pub fn offset(&self, dx: i64, dy: i64) -> Option<&'a T> {
+        let x = usize::try_from(self.x as i64 + dx).ok()?;
+        let y = usize::try_from(self.y as i64 + dy).ok()?;
+        self.map.get(x, y)
+    }
+    pub fn neighbors4(&self) -> impl Iterator<Item = &'a T> + '_ {
+        Direction::OFFSETS
+            .into_iter()
+            .filter_map(|(dx, dy)| self.offset(dx, dy))
+    }
+    pub fn neighbors8(&self) -> impl Iterator<Item = &'a T> + '_ {
+        (-1..=1)
+            .flat_map(|dy| (-1..=1).map(move |dx| (dx, dy)))
+            .filter(|offset| *offset != (0, 0))
+            .filter_map(|(dx, dy)| self.offset(dx, dy))
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Outcome {
+    Fixpoint(usize),
+    MaxSteps(usize),
+}
+
+type Rule<'a, T> = Box<dyn FnMut(&Neighborhood<'_, T>) -> T + 'a>;
+type Callback<'a, T> = Box<dyn FnMut(usize, &Map2D<T>) + 'a>;
+
+pub struct Automaton<'a, T> {
+    current: Map2D<T>,
+    next: Map2D<T>,
+    rule: Rule<'a, T>,
+    on_step: Option<Callback<'a, T>>,
+    max_steps: Option<usize>,
+    steps: usize,
+}
+
+impl<'a, T: Clone + PartialEq> Automaton<'a, T> {
+    pub fn new<F>(initial: Map2D<T>, rule: F) -> Self
+    where
+        F: FnMut(&Neighborhood<'_, T>) -> T + 'a,
+    {
+        Self {
+            next: initial.clone(),
+            current: initial,
+            rule: Box::new(rule),
+            on_step: None,
+            max_steps: None,
+            steps: 0,
+        }
+    }
+    pub fn max_steps(mut self, max_steps: usize) -> Self {
+        self.max_steps = Some(max_steps);
+        self
+    }
+    pub fn on_step<F>(mut self, on_step: F) -> Self
+    where
+        F: FnMut(usize, &Map2D<T>) + 'a,
+    {
+        self.on_step = Some(Box::new(on_step));
+        self
+    }
+    pub fn state(&self) -> &Map2D<T> {
+        &self.current
+    }
+    pub fn into_state(self) -> Map2D<T> {
+        self.current
+    }
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+    pub fn step(&mut self) -> bool {
+        for y in 0..self.current.height() {
+            for x in 0..self.current.width() {
+                let neighborhood = Neighborhood {
+                    map: &self.current,
+                    x,
+                    y,
+                };
+                self.next[y][x] = (self.rule)(&neighborhood);
+            }
+        }
+        mem::swap(&mut self.current, &mut self.next);
+        self.steps += 1;
+
+        if let Some(on_step) = self.on_step.as_mut() {
+            on_step(self.steps, &self.current);
+        }
+        self.current != self.next
+    }
+    pub fn run(&mut self) -> Outcome {
+        loop {
+            if self
+                .max_steps
+                .is_some_and(|max_steps| self.steps >= max_steps)
+            {
+                return Outcome::MaxSteps(self.steps);
+            }
+            if !self.step() {
+                return Outcome::Fixpoint(self.steps);
+            }
+        }
+    }
+}
+
+impl<T: Clone + Hash + Eq> Automaton<'_, T> {
+    /// Jumps to step `n` (capped at `max_steps`) through cycle detection, so the intermediate
+    /// states are never observed; panics when an `on_step` callback is set.
+    pub fn run_to(&mut self, n: usize) -> &Map2D<T> {
+        assert!(
+            self.on_step.is_none(),
+            "run_to skips intermediate steps and cannot call on_step"
+        );
+        let target = self.max_steps.map_or(n, |max_steps| n.min(max_steps));
+        let remaining = target.saturating_sub(self.steps);
+        let rule = &mut self.rule;
+
+        self.current = cycle::fast_forward(
+            self.current.clone(),
+            |state| {
+                Map2D::from_fn(state.height(), state.width(), |x, y| {
+                    rule(&Neighborhood { map: state, x, y })
+                })
+            },
+            remaining,
+        );
+        self.next.clone_from(&self.current);
+        self.steps += remaining;
+        &self.current
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid;
+
+    fn life(neighborhood: &Neighborhood<'_, char>) -> char {
+        let alive = neighborhood
+            .neighbors8()
+            .filter(|cell| **cell == '#')
+            .count();
+        match (neighborhood.cell(), alive) {
+            ('#', 2 | 3) | ('.', 3) => '#',
+            _ => '.',
+        }
+    }
+
+    #[test]
+    fn fixpoint_and_max_steps() {
+        let block = grid("....\n.##.\n.##.\n....\n");
+        let mut automaton = Automaton::new(block.clone(), life);
+        assert_eq!(automaton.run(), Outcome::Fixpoint(1));
+        assert_eq!(automaton.state(), &block);
+
+        let blinker = grid(".....\n..#..\n..#..\n..#..\n.....\n");
+        let mut frames = vec![];
+        let outcome = Automaton::new(blinker, life)
+            .max_steps(3)
+            .on_step(|step, state| frames.push((step, state.to_string())))
+            .run();
+        assert_eq!(outcome, Outcome::MaxSteps(3));
+        assert_eq!(frames.len(), 3);
+        assert_eq!(frames[0].1, ".....\n.....\n.###.\n.....\n.....\n");
+    }
+
+    #[test]
+    fn run_to_with_cycle() {
+        let blinker = grid(".....\n..#..\n..#..\n..#..\n.....\n");
+        let mut automaton = Automaton::new(blinker.clone(), life);
+
+        assert_eq!(automaton.run_to(1_000_000_000), &blinker);
+        automaton.step();
+        assert_eq!(automaton.steps(), 1_000_000_001);
+        assert_ne!(automaton.state(), &blinker);
+    }
+
+    #[test]
+    fn run_to_settings() {
+        let block = grid("....\n.##.\n.##.\n....\n");
+        let mut automaton = Automaton::new(block, life).max_steps(10);
+
+        automaton.run_to(1_000);
+        assert_eq!(automaton.steps(), 10);
+        assert!(!automaton.step());
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot call on_step")]
+    fn run_to_with_callback() {
+        let blinker = grid(".....\n..#..\n..#..\n..#..\n.....\n");
+        Automaton::new(blinker, life).on_step(|_, _| ()).run_to(5);
+    }
+}
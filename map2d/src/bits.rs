@@ -0,0 +1,170 @@
+use std::fmt::{self, Display, Write};
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct BitMap2D {
+    words: Box<[u64]>,
+    words_per_row: usize,
+    height: usize,
+    width: usize,
+}
+
+impl BitMap2D {
+    pub fn new(height: usize, width: usize) -> Self {
+        let words_per_row = width.div_ceil(WORD_BITS);
+        Self {
+            words: vec![0; words_per_row * height].into_boxed_slice(),
+            words_per_row,
+            height,
+            width,
+        }
+    }
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    pub fn get(&self, x: usize, y: usize) -> Option<bool> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        Some(self.test(x, y))
+    }
+    pub fn test(&self, x: usize, y: usize) -> bool {
+        let (index, mask) = self.locate(x, y);
+        self.words[index] & mask != 0
+    }
+    pub fn set(&mut self, x: usize, y: usize) {
+        let (index, mask) = self.locate(x, y);
+        self.words[index] |= mask;
+    }
+    pub fn clear(&mut self, x: usize, y: usize) {
+        let (index, mask) = self.locate(x, y);
+        self.words[index] &= !mask;
+    }
+    pub fn clear_all(&mut self) {
+        self.words.fill(0);
+    }
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+    pub fn row(&self, y: usize) -> &[u64] {
+        &self.words[y * self.words_per_row..(y + 1) * self.words_per_row]
+    }
+    pub fn row_count_ones(&self, y: usize) -> usize {
+        self.row(y)
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+    pub fn ones(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..self.height).flat_map(move |y| {
+            (0..self.width)
+                .filter(move |x| self.test(*x, y))
+                .map(move |x| (x, y))
+        })
+    }
+    pub fn union_with(&mut self, other: &BitMap2D) {
+        self.zip_words(other, |a, b| *a |= b);
+    }
+    pub fn intersect_with(&mut self, other: &BitMap2D) {
+        self.zip_words(other, |a, b| *a &= b);
+    }
+    fn zip_words(&mut self, other: &BitMap2D, f: impl Fn(&mut u64, u64)) {
+        assert!(
+            self.width == other.width && self.height == other.height,
+            "mismatched bitmap dimensions {}x{} and {}x{}",
+            self.width,
+            self.height,
+            other.width,
+            other.height
+        );
+        self.words
+            .iter_mut()
+            .zip(other.words.iter())
+            .for_each(|(a, b)| f(a, *b));
+    }
+    fn locate(&self, x: usize, y: usize) -> (usize, u64) {
+        assert!(
+            x < self.width && y < self.height,
+            "position ({x}, {y}) out of bounds for a {}x{} bitmap",
+            self.width,
+            self.height
+        );
+        let index = y * self.words_per_row + x / WORD_BITS;
+        (index, 1 << (x % WORD_BITS))
+    }
+}
+
+impl BitOrAssign<&BitMap2D> for BitMap2D {
+    fn bitor_assign(&mut self, other: &BitMap2D) {
+        self.union_with(other);
+    }
+}
+
+impl BitAndAssign<&BitMap2D> for BitMap2D {
+    fn bitand_assign(&mut self, other: &BitMap2D) {
+        self.intersect_with(other);
+    }
+}
+
+impl BitOr for &BitMap2D {
+    type Output = BitMap2D;
+
+    fn bitor(self, other: &BitMap2D) -> BitMap2D {
+        let mut out = self.clone();
+        out |= other;
+        out
+    }
+}
+
+impl BitAnd for &BitMap2D {
+    type Output = BitMap2D;
+
+    fn bitand(self, other: &BitMap2D) -> BitMap2D {
+        let mut out = self.clone();
+        out &= other;
+        out
+    }
+}
+
+impl Display for BitMap2D {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.height {
+            for x in 0..self.width {
+                f.write_char(if self.test(x, y) { '#' } else { '.' })?;
+            }
+            f.write_char('\n')?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_and_count() {
+        let mut a = BitMap2D::new(2, 70);
+        let mut b = BitMap2D::new(2, 70);
+        a.set(0, 0);
+        a.set(69, 1);
+        b.set(69, 1);
+        b.set(64, 0);
+
+        assert_eq!(a.count_ones(), 2);
+        assert_eq!((&a | &b).count_ones(), 3);
+        assert_eq!((&a & &b).ones().collect::<Vec<_>>(), vec![(69, 1)]);
+
+        a.clear(0, 0);
+        assert_eq!(a.row_count_ones(0), 0);
+        assert_eq!(a.get(70, 0), None);
+    }
+}
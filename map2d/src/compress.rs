@@ -0,0 +1,169 @@
+use crate::Map2D;
+
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct CompressedAxis {
+    starts: Vec<i64>,
+    end: i64,
+}
+
+impl CompressedAxis {
+    pub fn new<I>(values: I) -> Self
+    where
+        I: IntoIterator<Item = i64>,
+    {
+        let mut values: Vec<i64> = values.into_iter().collect();
+        values.sort_unstable();
+        values.dedup();
+
+        let mut starts = vec![];
+        for (i, value) in values.iter().enumerate() {
+            starts.push(*value);
+            if values.get(i + 1).is_some_and(|next| *next > value + 1) {
+                starts.push(value + 1);
+            }
+        }
+        let end = values.last().map_or(0, |last| last + 1);
+        Self { starts, end }
+    }
+    pub fn len(&self) -> usize {
+        self.starts.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.starts.is_empty()
+    }
+    pub fn index_of(&self, value: i64) -> Option<usize> {
+        if self.starts.first().is_none_or(|first| value < *first) || value >= self.end {
+            return None;
+        }
+        Some(self.starts.partition_point(|start| *start <= value) - 1)
+    }
+    pub fn start(&self, index: usize) -> i64 {
+        self.starts[index]
+    }
+    pub fn width(&self, index: usize) -> i64 {
+        self.starts.get(index + 1).unwrap_or(&self.end) - self.starts[index]
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub struct Cell<T> {
+    pub value: T,
+    pub origin: (i64, i64),
+    pub width: i64,
+    pub height: i64,
+}
+
+impl<T> Cell<T> {
+    pub fn area(&self) -> i64 {
+        self.width * self.height
+    }
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct Compression {
+    columns: CompressedAxis,
+    rows: CompressedAxis,
+}
+
+impl Compression {
+    pub fn new<I>(points: I) -> Self
+    where
+        I: IntoIterator<Item = (i64, i64)>,
+    {
+        let (xs, ys): (Vec<i64>, Vec<i64>) = points.into_iter().unzip();
+        Self::from_axes(xs, ys)
+    }
+    pub fn from_axes<X, Y>(xs: X, ys: Y) -> Self
+    where
+        X: IntoIterator<Item = i64>,
+        Y: IntoIterator<Item = i64>,
+    {
+        Self {
+            columns: CompressedAxis::new(xs),
+            rows: CompressedAxis::new(ys),
+        }
+    }
+    pub fn columns(&self) -> &CompressedAxis {
+        &self.columns
+    }
+    pub fn rows(&self) -> &CompressedAxis {
+        &self.rows
+    }
+    pub fn compress(&self, (x, y): (i64, i64)) -> Option<(usize, usize)> {
+        Some((self.columns.index_of(x)?, self.rows.index_of(y)?))
+    }
+    pub fn real(&self, (x, y): (usize, usize)) -> (i64, i64) {
+        (self.columns.start(x), self.rows.start(y))
+    }
+    pub fn map<T, F>(&self, mut f: F) -> Map2D<Cell<T>>
+    where
+        F: FnMut(i64, i64) -> T,
+    {
+        Map2D::from_fn(self.rows.len(), self.columns.len(), |x, y| {
+            let origin = self.real((x, y));
+            Cell {
+                value: f(origin.0, origin.1),
+                origin,
+                width: self.columns.width(x),
+                height: self.rows.width(y),
+            }
+        })
+    }
+    pub fn distance(&self, a: (usize, usize), b: (usize, usize)) -> i64 {
+        let (a, b) = (self.real(a), self.real(b));
+        (a.0 - b.0).abs() + (a.1 - b.1).abs()
+    }
+}
+
+pub fn area<T, F>(map: &Map2D<Cell<T>>, mut predicate: F) -> i64
+where
+    F: FnMut(&T) -> bool,
+{
+    map.rows()
+        .flat_map(|row| row.iter())
+        .filter(|cell| predicate(&cell.value))
+        .map(Cell::area)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn axis() {
+        let axis = CompressedAxis::new([10, 3, 4, 1_000_000]);
+
+        assert_eq!(axis.len(), 6);
+        assert_eq!(axis.index_of(2), None);
+        assert_eq!(axis.index_of(4), Some(1));
+        assert_eq!(axis.index_of(7), Some(2));
+        assert_eq!(axis.width(2), 5);
+        assert_eq!(axis.width(4), 1_000_000 - 11);
+        assert_eq!(axis.index_of(1_000_000), Some(5));
+        assert_eq!(axis.index_of(1_000_001), None);
+        assert!(CompressedAxis::new([]).index_of(0).is_none());
+    }
+
+    #[test]
+    fn area_and_distance() {
+        let corners = [
+            (0, 0),
+            (2_000_000, 0),
+            (2_000_000, 1_000_000),
+            (0, 1_000_000),
+        ];
+        let compression = Compression::new(corners);
+        let inside = |x: i64, y: i64| x <= 2_000_000 && y <= 1_000_000;
+        let map = compression.map(inside);
+
+        assert_eq!((map.width(), map.height()), (3, 3));
+        assert_eq!(area(&map, |inside| *inside), 2_000_001 * 1_000_001);
+
+        let a = compression.compress((0, 0)).unwrap();
+        let b = compression.compress((2_000_000, 1_000_000)).unwrap();
+        assert_eq!(compression.distance(a, b), 3_000_000);
+        assert_eq!(map[b.1][b.0].origin, (2_000_000, 1_000_000));
+        assert_eq!(compression.compress((1, 999_999)), Some((1, 1)));
+    }
+}
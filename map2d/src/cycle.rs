@@ -0,0 +1,142 @@
+use crate::Map2D;
+use std::collections::hash_map::{DefaultHasher, HashMap};
+use std::hash::{Hash, Hasher};
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Cycle {
+    pub prefix: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    pub fn index_of(&self, step: usize) -> usize {
+        if step < self.prefix {
+            return step;
+        }
+        self.prefix + (step - self.prefix) % self.period
+    }
+}
+
+pub fn fingerprint<S: Hash + ?Sized>(state: &S) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    state.hash(&mut hasher);
+    hasher.finish()
+}
+
+pub fn brent<S, F>(initial: S, mut step: F) -> Cycle
+where
+    S: Clone + Eq,
+    F: FnMut(&S) -> S,
+{
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+    }
+
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..period {
+        hare = step(&hare);
+    }
+
+    let mut prefix = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    Cycle { prefix, period }
+}
+
+pub fn detect<S, F>(initial: S, mut step: F) -> Cycle
+where
+    S: Clone + Hash + Eq,
+    F: FnMut(&S) -> S,
+{
+    let mut seen = HashMap::new();
+    let mut state = initial;
+
+    for index in 0.. {
+        if let Some(prefix) = seen.insert(state.clone(), index) {
+            return Cycle {
+                prefix,
+                period: index - prefix,
+            };
+        }
+        state = step(&state);
+    }
+    unreachable!()
+}
+
+pub fn fast_forward<S, F>(initial: S, mut step: F, n: usize) -> S
+where
+    S: Clone + Hash + Eq,
+    F: FnMut(&S) -> S,
+{
+    let mut seen = HashMap::new();
+    let mut history = vec![];
+    let mut state = initial;
+
+    for index in 0..n {
+        if let Some(prefix) = seen.insert(state.clone(), index) {
+            let cycle = Cycle {
+                prefix,
+                period: index - prefix,
+            };
+            return history.swap_remove(cycle.index_of(n));
+        }
+        let next = step(&state);
+        history.push(state);
+        state = next;
+    }
+    state
+}
+
+impl<T: Hash> Map2D<T> {
+    pub fn fingerprint(&self) -> u64 {
+        fingerprint(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0, 1, 2, 3, 4, 5, 6, 3, 4, 5, 6, ...
+    fn step(state: &u32) -> u32 {
+        match state {
+            6 => 3,
+            _ => state + 1,
+        }
+    }
+
+    #[test]
+    fn detectors_agree() {
+        let expected = Cycle {
+            prefix: 3,
+            period: 4,
+        };
+        assert_eq!(brent(0, step), expected);
+        assert_eq!(detect(0, step), expected);
+        assert_eq!(expected.index_of(2), 2);
+        assert_eq!(expected.index_of(1_000_000_000), 4);
+    }
+
+    #[test]
+    fn fast_forward_to_step() {
+        assert_eq!(fast_forward(0, step, 0), 0);
+        assert_eq!(fast_forward(0, step, 5), 5);
+        assert_eq!(fast_forward(0, step, 1_000_000_000), 4);
+    }
+}
@@ -0,0 +1,183 @@
+use crate::render::{Color, Overlay, Renderer, Style};
+use crate::Map2D;
+use std::fmt::{self, Display, Write};
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Change<'a, T> {
+    pub position: (usize, usize),
+    pub old: &'a T,
+    pub new: &'a T,
+}
+
+#[derive(Debug, Clone)]
+pub struct Diff<'a, T> {
+    old: &'a Map2D<T>,
+    new: &'a Map2D<T>,
+    changes: Vec<Change<'a, T>>,
+}
+
+impl<'a, T> Diff<'a, T> {
+    pub fn changes(&self) -> &[Change<'a, T>] {
+        &self.changes
+    }
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty() && !self.resized()
+    }
+    pub fn resized(&self) -> bool {
+        self.old.width() != self.new.width() || self.old.height() != self.new.height()
+    }
+    pub fn highlighted(&self) -> Renderer<'a, T> {
+        let changed = self.changes.iter().map(|change| change.position);
+        self.new.render().overlay(Overlay::points(
+            changed,
+            Style::default().foreground(Color::Red).bold(),
+        ))
+    }
+}
+
+impl<T: Display> Display for Diff<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.resized() {
+            writeln!(
+                f,
+                "dimensions differ: {}x{} vs {}x{}",
+                self.old.width(),
+                self.old.height(),
+                self.new.width(),
+                self.new.height()
+            )?;
+        }
+
+        let height = self.old.height().max(self.new.height());
+        let cell_width = [self.old, self.new]
+            .iter()
+            .flat_map(|map| map.iter_with_positions())
+            .map(|(_, cell)| cell.to_string().chars().count())
+            .max()
+            .unwrap_or(1)
+            .max(1);
+        let separator = if cell_width > 1 { " " } else { "" };
+        let line = |width: usize, cell: &dyn Fn(usize) -> String| -> String {
+            (0..width)
+                .map(|x| format!("{:>cell_width$}", cell(x)))
+                .collect::<Vec<_>>()
+                .join(separator)
+        };
+
+        for y in 0..height {
+            let cell_string =
+                |map: &Map2D<T>, x: usize| map.get(x, y).map_or(String::new(), ToString::to_string);
+            let left = line(self.old.width(), &|x| cell_string(self.old, x));
+            let right = line(self.new.width(), &|x| cell_string(self.new, x));
+            writeln!(f, "{left} | {right}")?;
+
+            let changed: Vec<usize> = self
+                .changes
+                .iter()
+                .filter(|change| change.position.1 == y)
+                .map(|change| change.position.0)
+                .collect();
+            if changed.is_empty() {
+                continue;
+            }
+            let caret = |x: usize| match changed.contains(&x) {
+                true => "^".repeat(cell_width),
+                false => String::new(),
+            };
+            f.write_str(&line(self.old.width(), &caret))?;
+            f.write_str(" | ")?;
+            f.write_str(line(self.new.width(), &caret).trim_end())?;
+            f.write_char('\n')?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: PartialEq> Map2D<T> {
+    pub fn diff<'a>(&'a self, other: &'a Map2D<T>) -> Diff<'a, T> {
+        let changes = self
+            .iter_with_positions()
+            .filter_map(|((x, y), old)| {
+                let new = other.get(x, y)?;
+                (old != new).then_some(Change {
+                    position: (x, y),
+                    old,
+                    new,
+                })
+            })
+            .collect();
+
+        Diff {
+            old: self,
+            new: other,
+            changes,
+        }
+    }
+}
+
+#[macro_export]
+macro_rules! assert_map_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                let diff = $crate::Map2D::diff(left, right);
+                if !diff.is_empty() {
+                    panic!(
+                        "assertion `left == right` failed: maps differ in {} cells\n{diff}",
+                        diff.len()
+                    );
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid;
+
+    #[test]
+    fn changes_and_rendering() {
+        let old = grid("O..\n.#.\n");
+        let new = grid(".O.\n.#.\n");
+        let diff = old.diff(&new);
+
+        assert_eq!(diff.len(), 2);
+        assert_eq!(
+            diff.changes()[0],
+            Change {
+                position: (0, 0),
+                old: &'O',
+                new: &'.'
+            }
+        );
+        assert_eq!(diff.to_string(), "O.. | .O.\n^^  | ^^\n.#. | .#.\n");
+        assert_eq!(
+            diff.highlighted().to_string(),
+            "\x1b[1;31m.\x1b[0m\x1b[1;31mO\x1b[0m.\n.#.\n"
+        );
+        assert!(old.diff(&old).is_empty());
+        assert!(!old.diff(&grid("O..\n")).is_empty());
+    }
+
+    #[test]
+    fn wide_cells() {
+        let old = Map2D::from_fn(1, 3, |x, _| x * 5);
+        let new = Map2D::from_fn(1, 3, |x, _| if x == 1 { 50 } else { x * 5 });
+
+        assert_eq!(
+            old.diff(&new).to_string(),
+            " 0  5 10 |  0 50 10\n   ^^    |    ^^\n"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "maps differ in 1 cells")]
+    fn assertion() {
+        assert_map_eq!(grid("ab\n"), grid("ac\n"));
+    }
+}
@@ -0,0 +1,100 @@
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct Polygon {
+    double_area: i64,
+    boundary_points: i64,
+    vertex_count: usize,
+}
+
+impl Polygon {
+    pub fn double_area(&self) -> i64 {
+        self.double_area
+    }
+    pub fn area(&self) -> f64 {
+        self.double_area as f64 / 2.0
+    }
+    pub fn boundary_points(&self) -> i64 {
+        self.boundary_points
+    }
+    pub fn interior_points(&self) -> i64 {
+        if self.vertex_count < 3 || self.double_area == 0 {
+            return 0;
+        }
+        // Pick's theorem: A = I + B / 2 - 1
+        ((self.double_area - self.boundary_points + 2) / 2).max(0)
+    }
+}
+
+impl FromIterator<(i64, i64)> for Polygon {
+    fn from_iter<I: IntoIterator<Item = (i64, i64)>>(iter: I) -> Self {
+        let mut vertices = iter.into_iter();
+        let Some(first) = vertices.next() else {
+            return Self::default();
+        };
+
+        let mut shoelace = 0;
+        let mut boundary_points = 0;
+        let mut vertex_count = 1;
+        let mut previous = first;
+
+        for vertex in vertices.chain([first]) {
+            shoelace += previous.0 * vertex.1 - vertex.0 * previous.1;
+            boundary_points += gcd(vertex.0.abs_diff(previous.0), vertex.1.abs_diff(previous.1));
+            vertex_count += 1;
+            previous = vertex;
+        }
+
+        Self {
+            double_area: shoelace.abs(),
+            boundary_points: boundary_points as i64,
+            vertex_count: vertex_count - 1,
+        }
+    }
+}
+
+pub fn shoelace<I: IntoIterator<Item = (i64, i64)>>(vertices: I) -> f64 {
+    Polygon::from_iter(vertices).area()
+}
+
+pub fn interior_points<I: IntoIterator<Item = (i64, i64)>>(vertices: I) -> i64 {
+    Polygon::from_iter(vertices).interior_points()
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    match b {
+        0 => a,
+        _ => gcd(b, a % b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vertices_and_paths_agree() {
+        let square = [(0, 0), (4, 0), (4, 4), (0, 4)];
+        let path = (0..4)
+            .map(|x| (x, 0))
+            .chain((0..4).map(|y| (4, y)))
+            .chain((1..=4).rev().map(|x| (x, 4)))
+            .chain((1..=4).rev().map(|y| (0, y)));
+
+        let from_vertices = Polygon::from_iter(square);
+        let from_path = Polygon::from_iter(path);
+
+        assert_eq!(from_vertices.double_area(), 32);
+        assert_eq!(from_vertices.boundary_points(), 16);
+        assert_eq!(from_vertices.interior_points(), 9);
+        assert_eq!(from_path.double_area(), 32);
+        assert_eq!(from_path.interior_points(), 9);
+
+        assert_eq!(shoelace([(0, 0), (3, 0), (0, 3)]), 4.5);
+        assert_eq!(interior_points([(0, 0), (1, 0)]), 0);
+    }
+
+    #[test]
+    fn collinear_paths() {
+        assert_eq!(interior_points([(0, 0), (1, 0), (2, 0)]), 0);
+        assert_eq!(interior_points((0..10).map(|y| (3, y))), 0);
+    }
+}
@@ -0,0 +1,101 @@
+use crate::Map2D;
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub struct Bounds {
+    pub min_x: i64,
+    pub min_y: i64,
+    pub max_x: i64,
+    pub max_y: i64,
+}
+
+impl Bounds {
+    pub fn new(min_x: i64, min_y: i64, max_x: i64, max_y: i64) -> Self {
+        Self {
+            min_x,
+            min_y,
+            max_x,
+            max_y,
+        }
+    }
+    pub fn width(&self) -> usize {
+        (self.max_x - self.min_x).max(0) as usize
+    }
+    pub fn height(&self) -> usize {
+        (self.max_y - self.min_y).max(0) as usize
+    }
+    pub fn is_empty(&self) -> bool {
+        self.width() == 0 || self.height() == 0
+    }
+    pub fn contains(&self, x: i64, y: i64) -> bool {
+        (self.min_x..self.max_x).contains(&x) && (self.min_y..self.max_y).contains(&y)
+    }
+    pub fn extend(&mut self, x: i64, y: i64) {
+        if self.is_empty() {
+            *self = Self::new(x, y, x + 1, y + 1);
+            return;
+        }
+        self.min_x = self.min_x.min(x);
+        self.min_y = self.min_y.min(y);
+        self.max_x = self.max_x.max(x + 1);
+        self.max_y = self.max_y.max(y + 1);
+    }
+    pub fn positions(self) -> impl Iterator<Item = (i64, i64)> {
+        (self.min_y..self.max_y).flat_map(move |y| (self.min_x..self.max_x).map(move |x| (x, y)))
+    }
+}
+
+impl FromIterator<(i64, i64)> for Bounds {
+    fn from_iter<I: IntoIterator<Item = (i64, i64)>>(iter: I) -> Self {
+        iter.into_iter()
+            .fold(Self::default(), |mut bounds, (x, y)| {
+                bounds.extend(x, y);
+                bounds
+            })
+    }
+}
+
+pub trait Grid {
+    type Cell;
+
+    fn bounds(&self) -> Bounds;
+    fn cell(&self, x: i64, y: i64) -> Option<&Self::Cell>;
+
+    fn positions(&self) -> impl Iterator<Item = (i64, i64)> {
+        self.bounds().positions()
+    }
+    fn cells(&self) -> impl Iterator<Item = ((i64, i64), &Self::Cell)> {
+        self.positions()
+            .filter_map(|(x, y)| Some(((x, y), self.cell(x, y)?)))
+    }
+}
+
+pub trait GridMut: Grid {
+    fn cell_mut(&mut self, x: i64, y: i64) -> Option<&mut Self::Cell>;
+
+    fn set(&mut self, x: i64, y: i64, value: Self::Cell) -> bool {
+        match self.cell_mut(x, y) {
+            Some(cell) => {
+                *cell = value;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl<T> Grid for Map2D<T> {
+    type Cell = T;
+
+    fn bounds(&self) -> Bounds {
+        Bounds::new(0, 0, self.width() as i64, self.height() as i64)
+    }
+    fn cell(&self, x: i64, y: i64) -> Option<&T> {
+        self.get(usize::try_from(x).ok()?, usize::try_from(y).ok()?)
+    }
+}
+
+impl<T> GridMut for Map2D<T> {
+    fn cell_mut(&mut self, x: i64, y: i64) -> Option<&mut T> {
+        self.get_mut(usize::try_from(x).ok()?, usize::try_from(y).ok()?)
+    }
+}
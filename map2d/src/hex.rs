@@ -0,0 +1,259 @@
+use crate::Map2D;
+use anyhow::{bail, Result};
+use std::fmt;
+use std::iter;
+use std::ops::{Add, Mul, Sub};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum HexDirection {
+    North,
+    NorthEast,
+    SouthEast,
+    South,
+    SouthWest,
+    NorthWest,
+}
+
+impl HexDirection {
+    pub const ALL: [HexDirection; 6] = [
+        HexDirection::North,
+        HexDirection::NorthEast,
+        HexDirection::SouthEast,
+        HexDirection::South,
+        HexDirection::SouthWest,
+        HexDirection::NorthWest,
+    ];
+
+    pub fn offset(self) -> Hex {
+        match self {
+            HexDirection::North => Hex::new(0, -1),
+            HexDirection::NorthEast => Hex::new(1, -1),
+            HexDirection::SouthEast => Hex::new(1, 0),
+            HexDirection::South => Hex::new(0, 1),
+            HexDirection::SouthWest => Hex::new(-1, 1),
+            HexDirection::NorthWest => Hex::new(-1, 0),
+        }
+    }
+    pub fn opposite(self) -> HexDirection {
+        self.turn(3)
+    }
+    pub fn turn(self, steps: i64) -> HexDirection {
+        let index = Self::ALL.iter().position(|d| *d == self).unwrap() as i64;
+        Self::ALL[(index + steps).rem_euclid(6) as usize]
+    }
+}
+
+impl FromStr for HexDirection {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(match s.trim().to_ascii_lowercase().as_str() {
+            "n" => HexDirection::North,
+            "ne" => HexDirection::NorthEast,
+            "se" => HexDirection::SouthEast,
+            "s" => HexDirection::South,
+            "sw" => HexDirection::SouthWest,
+            "nw" => HexDirection::NorthWest,
+            _ => bail!("invalid hex direction '{s}'"),
+        })
+    }
+}
+
+impl fmt::Display for HexDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            HexDirection::North => "n",
+            HexDirection::NorthEast => "ne",
+            HexDirection::SouthEast => "se",
+            HexDirection::South => "s",
+            HexDirection::SouthWest => "sw",
+            HexDirection::NorthWest => "nw",
+        };
+        f.write_str(name)
+    }
+}
+
+pub fn parse_directions(input: &str) -> Result<Vec<HexDirection>> {
+    input
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|token| !token.is_empty())
+        .map(str::parse)
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub struct Hex {
+    pub q: i64,
+    pub r: i64,
+}
+
+impl Hex {
+    pub const fn new(q: i64, r: i64) -> Self {
+        Self { q, r }
+    }
+    pub fn from_cube(q: i64, r: i64, s: i64) -> Result<Self> {
+        if q + r + s != 0 {
+            bail!("invalid cube coordinates ({q}, {r}, {s}), expected q + r + s = 0");
+        }
+        Ok(Self::new(q, r))
+    }
+    pub fn s(self) -> i64 {
+        -self.q - self.r
+    }
+    pub fn cube(self) -> (i64, i64, i64) {
+        (self.q, self.r, self.s())
+    }
+    pub fn neighbor(self, direction: HexDirection) -> Hex {
+        self + direction.offset()
+    }
+    pub fn neighbors(self) -> impl Iterator<Item = Hex> {
+        HexDirection::ALL.into_iter().map(move |d| self.neighbor(d))
+    }
+    pub fn walk<I>(self, directions: I) -> Hex
+    where
+        I: IntoIterator<Item = HexDirection>,
+    {
+        directions.into_iter().fold(self, Hex::neighbor)
+    }
+    pub fn length(self) -> i64 {
+        (self.q.abs() + self.r.abs() + self.s().abs()) / 2
+    }
+    pub fn distance(self, other: Hex) -> i64 {
+        (self - other).length()
+    }
+    pub fn ring(self, radius: usize) -> impl Iterator<Item = Hex> {
+        let walk = [
+            HexDirection::SouthEast,
+            HexDirection::NorthEast,
+            HexDirection::North,
+            HexDirection::NorthWest,
+            HexDirection::SouthWest,
+            HexDirection::South,
+        ];
+        let start = self + HexDirection::SouthWest.offset() * radius as i64;
+
+        let ring = walk
+            .into_iter()
+            .flat_map(move |direction| iter::repeat_n(direction, radius))
+            .scan(start, |hex, direction| {
+                let current = *hex;
+                *hex = hex.neighbor(direction);
+                Some(current)
+            });
+        iter::once(self).filter(move |_| radius == 0).chain(ring)
+    }
+    pub fn spiral(self, radius: usize) -> impl Iterator<Item = Hex> {
+        (0..=radius).flat_map(move |r| self.ring(r))
+    }
+    pub fn from_offset((x, y): (i64, i64)) -> Self {
+        Self::new(x, y - (x - (x & 1)) / 2)
+    }
+    pub fn to_offset(self) -> (i64, i64) {
+        (self.q, self.r + (self.q - (self.q & 1)) / 2)
+    }
+}
+
+impl Add for Hex {
+    type Output = Hex;
+
+    fn add(self, other: Hex) -> Hex {
+        Hex::new(self.q + other.q, self.r + other.r)
+    }
+}
+
+impl Sub for Hex {
+    type Output = Hex;
+
+    fn sub(self, other: Hex) -> Hex {
+        Hex::new(self.q - other.q, self.r - other.r)
+    }
+}
+
+impl Mul<i64> for Hex {
+    type Output = Hex;
+
+    fn mul(self, factor: i64) -> Hex {
+        Hex::new(self.q * factor, self.r * factor)
+    }
+}
+
+impl<T> Map2D<T> {
+    pub fn hex(&self, hex: Hex) -> Option<&T> {
+        let (x, y) = self.hex_position(hex)?;
+        Some(&self[y][x])
+    }
+    pub fn hex_mut(&mut self, hex: Hex) -> Option<&mut T> {
+        let (x, y) = self.hex_position(hex)?;
+        Some(&mut self[y][x])
+    }
+    pub fn hex_neighbors(&self, hex: Hex) -> impl Iterator<Item = (Hex, &T)> {
+        hex.neighbors()
+            .filter_map(move |neighbor| Some((neighbor, self.hex(neighbor)?)))
+    }
+    pub fn iter_hexes(&self) -> impl Iterator<Item = (Hex, &T)> {
+        self.iter_with_positions()
+            .map(|((x, y), cell)| (Hex::from_offset((x as i64, y as i64)), cell))
+    }
+    fn hex_position(&self, hex: Hex) -> Option<(usize, usize)> {
+        let (x, y) = hex.to_offset();
+        let (x, y) = (usize::try_from(x).ok()?, usize::try_from(y).ok()?);
+        (x < self.width() && y < self.height()).then_some((x, y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn directions_and_distance() -> Result<()> {
+        let origin = Hex::default();
+        let walk =
+            |input| -> Result<i64> { Ok(origin.walk(parse_directions(input)?).distance(origin)) };
+
+        assert_eq!(walk("ne,ne,ne")?, 3);
+        assert_eq!(walk("ne,ne,sw,sw")?, 0);
+        assert_eq!(walk("ne,ne,s,s")?, 2);
+        assert_eq!(walk("se,sw,se,sw,sw")?, 3);
+        assert!(parse_directions("n,up").is_err());
+        assert_eq!(HexDirection::NorthWest.opposite().to_string(), "se");
+        assert!(Hex::from_cube(1, 1, 1).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn rings_and_spirals() {
+        let center = Hex::new(2, -1);
+
+        assert_eq!(center.ring(0).collect::<Vec<_>>(), vec![center]);
+        for radius in 1..4 {
+            let ring: Vec<_> = center.ring(radius).collect();
+            assert_eq!(ring.len(), 6 * radius);
+            assert!(ring.iter().all(|hex| hex.distance(center) == radius as i64));
+        }
+        assert_eq!(center.spiral(2).count(), 19);
+    }
+
+    #[test]
+    fn offset_storage() {
+        let mut map: Map2D<u8> = Map2D::new(3, 4);
+        for (x, y) in [(0, 0), (1, 0), (3, 2), (2, 1)] {
+            let hex = Hex::from_offset((x, y));
+            assert_eq!(hex.to_offset(), (x, y));
+        }
+
+        let center = Hex::from_offset((1, 1));
+        for neighbor in center.neighbors() {
+            if let Some(cell) = map.hex_mut(neighbor) {
+                *cell = 1;
+            }
+        }
+        assert_eq!(map.hex_neighbors(center).count(), 6);
+        assert_eq!(map.count(|cell| *cell == 1), 6);
+        assert_eq!(map.hex_neighbors(Hex::default()).count(), 2);
+        assert!(map
+            .iter_hexes()
+            .all(|(hex, cell)| map.hex(hex) == Some(cell)));
+    }
+}
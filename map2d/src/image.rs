@@ -0,0 +1,181 @@
+use crate::render::Color;
+use crate::Map2D;
+use anyhow::Result;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+pub type Rgb = [u8; 3];
+
+pub trait ToRgb {
+    fn to_rgb(&self) -> Rgb;
+}
+
+impl ToRgb for bool {
+    fn to_rgb(&self) -> Rgb {
+        match self {
+            true => [255, 255, 255],
+            false => [0, 0, 0],
+        }
+    }
+}
+
+impl ToRgb for Color {
+    fn to_rgb(&self) -> Rgb {
+        match *self {
+            Color::Black => [0, 0, 0],
+            Color::Red => [205, 49, 49],
+            Color::Green => [13, 188, 121],
+            Color::Yellow => [229, 229, 16],
+            Color::Blue => [36, 114, 200],
+            Color::Magenta => [188, 63, 188],
+            Color::Cyan => [17, 168, 205],
+            Color::White => [229, 229, 229],
+            Color::Rgb(r, g, b) => [r, g, b],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Image {
+    pixels: Box<[u8]>,
+    height: usize,
+    width: usize,
+}
+
+impl Image {
+    pub fn from_map<T, F>(map: &Map2D<T>, scale: usize, color: F) -> Self
+    where
+        F: Fn(&T) -> Rgb,
+    {
+        let width = map.width() * scale;
+        let height = map.height() * scale;
+        let mut pixels = Vec::with_capacity(width * height * 3);
+
+        for row in map.rows() {
+            let line: Vec<u8> = row
+                .iter()
+                .flat_map(|cell| color(cell).repeat(scale))
+                .collect();
+            for _ in 0..scale {
+                pixels.extend_from_slice(&line);
+            }
+        }
+
+        Self {
+            pixels: pixels.into_boxed_slice(),
+            height,
+            width,
+        }
+    }
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+    pub fn write_ppm<W: Write>(&self, mut writer: W) -> Result<()> {
+        write!(writer, "P6\n{} {}\n255\n", self.width, self.height)?;
+        writer.write_all(&self.pixels)?;
+        writer.flush()?;
+        Ok(())
+    }
+    #[cfg(feature = "png")]
+    pub fn write_png<W: Write>(&self, mut writer: W) -> Result<()> {
+        let mut encoder = png::Encoder::new(&mut writer, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut png = encoder.write_header()?;
+        png.write_image_data(&self.pixels)?;
+        png.finish()?;
+        writer.flush()?;
+        Ok(())
+    }
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        let write: fn(&Self, BufWriter<File>) -> Result<()> =
+            match path.extension().and_then(|extension| extension.to_str()) {
+                #[cfg(feature = "png")]
+                Some("png") => Self::write_png,
+                Some("ppm") => Self::write_ppm,
+                _ => anyhow::bail!("unsupported image format for '{}'", path.display()),
+            };
+
+        write(self, BufWriter::new(File::create(path)?))
+    }
+}
+
+impl<T: ToRgb> Map2D<T> {
+    pub fn to_image(&self, scale: usize) -> Image {
+        Image::from_map(self, scale, T::to_rgb)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid;
+
+    #[test]
+    fn ppm() -> Result<()> {
+        let map = grid("#.\n");
+        let image = Image::from_map(&map, 2, |cell| match cell {
+            '#' => [255, 0, 0],
+            _ => [0, 0, 0],
+        });
+
+        let mut out = vec![];
+        image.write_ppm(&mut out)?;
+
+        let mut expected = b"P6\n4 2\n255\n".to_vec();
+        let line = [[255, 0, 0], [255, 0, 0], [0, 0, 0], [0, 0, 0]].concat();
+        expected.extend(line.repeat(2));
+        assert_eq!(out, expected);
+
+        Ok(())
+    }
+
+    struct Full;
+
+    impl Write for Full {
+        fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+            Err(std::io::ErrorKind::StorageFull.into())
+        }
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn buffered_write_errors() {
+        let image = Map2D::<bool>::new(1, 1).to_image(1);
+
+        assert!(image.write_ppm(BufWriter::new(Full)).is_err());
+        #[cfg(feature = "png")]
+        assert!(image.write_png(BufWriter::new(Full)).is_err());
+    }
+
+    #[test]
+    fn save_unsupported() {
+        let path = std::env::temp_dir().join("map2d-save-unsupported.bmp");
+        let image = Map2D::<bool>::new(1, 1).to_image(1);
+
+        assert!(image.save(&path).is_err());
+        assert!(!path.exists());
+    }
+
+    #[cfg(feature = "png")]
+    #[test]
+    fn png() -> Result<()> {
+        let map: Map2D<bool> = Map2D::new(3, 2);
+        let mut out = vec![];
+        map.to_image(4).write_png(&mut out)?;
+
+        assert!(out.starts_with(b"\x89PNG\r\n\x1a\n"));
+        Ok(())
+    }
+}
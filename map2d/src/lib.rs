@@ -1,6 +1,50 @@
-use anyhow::{anyhow, bail, Error, Result};
+pub mod automaton;
+pub mod compress;
+pub mod cycle;
+pub mod diff;
+pub mod geometry;
+pub mod hex;
+pub mod image;
+#[cfg(feature = "rayon")]
+pub mod parallel;
+pub mod ray;
+pub mod record;
+pub mod render;
+pub mod search;
+pub mod symmetry;
+pub mod template;
+
+mod array;
+mod bits;
+mod direction;
+mod grid;
+mod packed;
+mod parse;
+mod prefix;
+mod rect;
+#[cfg(feature = "serde")]
+mod serialization;
+mod sparse;
+mod tiled;
+
+pub use array::ArrayGrid;
+pub use bits::BitMap2D;
+pub use direction::Direction;
+pub use grid::{Bounds, Grid, GridMut};
+pub use packed::{Packable, PackedMap2D};
+pub use parse::Parser;
+pub use prefix::{PrefixSum, SummedAreaTable};
+pub use rect::Rect;
+#[cfg(feature = "serde")]
+pub use serialization::as_strings;
+pub use sparse::SparseMap2D;
+pub use tiled::Tiled;
+
+use anyhow::{bail, Error, Result};
 use std::fmt::{self, Display, Write};
-use std::io::{BufRead, BufReader, Read};
+use std::io::Read;
+use std::iter;
+use std::mem;
 use std::ops::{Deref, DerefMut, Range};
 use std::slice::Iter;
 
@@ -78,19 +122,11 @@ impl<T> DoubleEndedIterator for Column<'_, T> {
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct Row<T> {
     inner: Box<[T]>,
 }
 
-impl<T: Default + Copy> Row<T> {
-    fn new(width: usize) -> Self {
-        Self {
-            inner: vec![T::default(); width].into_boxed_slice(),
-        }
-    }
-}
-
 impl<T> Row<T> {
     pub fn len(&self) -> usize {
         self.inner.len()
@@ -101,6 +137,36 @@ impl<T> Row<T> {
     pub fn iter(&self) -> Iter<'_, T> {
         self.inner.iter()
     }
+    pub fn into_vec(self) -> Vec<T> {
+        self.inner.into_vec()
+    }
+    fn insert(&mut self, index: usize, value: T) {
+        let mut cells = mem::take(&mut self.inner).into_vec();
+        cells.insert(index, value);
+        self.inner = cells.into_boxed_slice();
+    }
+    fn remove(&mut self, index: usize) -> T {
+        let mut cells = mem::take(&mut self.inner).into_vec();
+        let value = cells.remove(index);
+        self.inner = cells.into_boxed_slice();
+        value
+    }
+}
+
+impl<T> From<Vec<T>> for Row<T> {
+    fn from(cells: Vec<T>) -> Self {
+        Self {
+            inner: cells.into_boxed_slice(),
+        }
+    }
+}
+
+impl<T> FromIterator<T> for Row<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self {
+            inner: iter.into_iter().collect(),
+        }
+    }
 }
 
 impl<T> Deref for Row<T> {
@@ -152,25 +218,58 @@ where
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct Map2D<T> {
     inner: Box<[Row<T>]>,
     height: usize,
     width: usize,
 }
 
-impl<T: Default + Copy> Map2D<T> {
+impl<T: Default> Map2D<T> {
     pub fn new(height: usize, width: usize) -> Self {
-        let inner = vec![Row::new(width); height].into_boxed_slice();
+        Self::from_fn(height, width, |_, _| T::default())
+    }
+}
+
+impl<T> Map2D<T> {
+    pub fn from_rows(rows: Vec<Row<T>>) -> Result<Self> {
+        let width = rows.first().map_or(0, |row| row.len());
+
+        if let Some((n, row)) = rows.iter().enumerate().find(|(_, row)| row.len() != width) {
+            bail!(
+                "incorrect row width '{}' on line {n}, expected '{width}'",
+                row.len()
+            );
+        }
+
+        Ok(Self {
+            height: rows.len(),
+            inner: rows.into_boxed_slice(),
+            width,
+        })
+    }
+    pub fn from_fn<F>(height: usize, width: usize, mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        let inner = (0..height)
+            .map(|y| (0..width).map(|x| f(x, y)).collect())
+            .collect();
         Self {
             inner,
             height,
             width,
         }
     }
-}
-
-impl<T> Map2D<T> {
+    pub fn map<U, F>(&self, mut f: F) -> Map2D<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Map2D::from_fn(self.height, self.width, |x, y| f(&self[y][x]))
+    }
+    pub fn into_rows(self) -> impl Iterator<Item = Row<T>> {
+        self.inner.into_vec().into_iter()
+    }
     pub fn rows(&self) -> impl Iterator<Item = &Row<T>> {
         self.inner.iter()
     }
@@ -195,6 +294,134 @@ impl<T> Map2D<T> {
         }
         Some(&self[y][x])
     }
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        Some(&mut self[y][x])
+    }
+    pub fn iter_with_positions(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.rows()
+            .enumerate()
+            .flat_map(|(y, row)| row.iter().enumerate().map(move |(x, cell)| ((x, y), cell)))
+    }
+    pub fn positions_of<'a>(&'a self, value: &'a T) -> impl Iterator<Item = (usize, usize)> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter_with_positions()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(position, _)| position)
+    }
+    pub fn find<P>(&self, mut predicate: P) -> Option<(usize, usize)>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter_with_positions()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+    pub fn count<P>(&self, mut predicate: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.rows()
+            .map(|row| row.iter().filter(|cell| predicate(cell)).count())
+            .sum()
+    }
+    pub fn remove_row(&mut self, index: usize) -> Row<T> {
+        assert!(index < self.height, "row index '{index}' out of bounds");
+
+        let mut rows = mem::take(&mut self.inner).into_vec();
+        let row = rows.remove(index);
+        self.inner = rows.into_boxed_slice();
+        self.height -= 1;
+        row
+    }
+    pub fn remove_column(&mut self, index: usize) -> Vec<T> {
+        assert!(index < self.width, "column index '{index}' out of bounds");
+
+        let column = self.inner.iter_mut().map(|row| row.remove(index)).collect();
+        self.width -= 1;
+        column
+    }
+    pub fn crop(&mut self, rect: Rect) {
+        assert!(
+            rect.right() <= self.width && rect.bottom() <= self.height,
+            "crop {rect:?} out of bounds for a {}x{} map",
+            self.width,
+            self.height
+        );
+
+        self.inner = mem::take(&mut self.inner)
+            .into_vec()
+            .into_iter()
+            .skip(rect.y)
+            .take(rect.height)
+            .map(|row| {
+                let mut cells = row.inner.into_vec();
+                cells.truncate(rect.right());
+                cells.drain(..rect.x);
+                Row::from(cells)
+            })
+            .collect();
+        self.height = rect.height;
+        self.width = rect.width;
+    }
+}
+
+impl<T: Clone> Map2D<T> {
+    pub fn transpose(&self) -> Self {
+        Map2D::from_fn(self.width, self.height, |x, y| self[x][y].clone())
+    }
+    pub fn rotate_clockwise(&self) -> Self {
+        Map2D::from_fn(self.width, self.height, |x, y| {
+            self[self.height - 1 - x][y].clone()
+        })
+    }
+    pub fn flip_horizontal(&self) -> Self {
+        Map2D::from_fn(self.height, self.width, |x, y| {
+            self[y][self.width - 1 - x].clone()
+        })
+    }
+    pub fn insert_row(&mut self, index: usize, value: T) {
+        assert!(index <= self.height, "row index '{index}' out of bounds");
+
+        let mut rows = mem::take(&mut self.inner).into_vec();
+        rows.insert(index, Row::from(vec![value; self.width]));
+        self.inner = rows.into_boxed_slice();
+        self.height += 1;
+    }
+    pub fn insert_column(&mut self, index: usize, value: T) {
+        assert!(index <= self.width, "column index '{index}' out of bounds");
+
+        self.inner
+            .iter_mut()
+            .for_each(|row| row.insert(index, value.clone()));
+        self.width += 1;
+    }
+    pub fn pad(&mut self, border: usize, value: T) {
+        let width = self.width + 2 * border;
+        let filler = || Row::from(vec![value.clone(); width]);
+
+        let rows = mem::take(&mut self.inner)
+            .into_vec()
+            .into_iter()
+            .map(|row| {
+                iter::repeat_n(value.clone(), border)
+                    .chain(row.inner.into_vec())
+                    .chain(iter::repeat_n(value.clone(), border))
+                    .collect()
+            });
+
+        self.inner = iter::repeat_with(filler)
+            .take(border)
+            .chain(rows)
+            .chain(iter::repeat_with(filler).take(border))
+            .collect();
+        self.height += 2 * border;
+        self.width = width;
+    }
 }
 
 impl<T> Deref for Map2D<T> {
@@ -216,38 +443,7 @@ where
     <T as TryFrom<char>>::Error: Into<Error>,
 {
     pub fn from_reader<R: Read>(reader: R) -> Result<Self> {
-        let mut lines = BufReader::new(reader).lines().peekable();
-
-        let width = lines
-            .peek_mut()
-            .ok_or(anyhow!("no line in reader"))?
-            .as_ref()
-            .map_err(|error| anyhow!("error reading line: {error}"))
-            .map(|s| s.len())?;
-
-        let inner: Box<[Row<T>]> = lines
-            .enumerate()
-            .map(|(n, maybe_line)| {
-                let row = Row::try_from(maybe_line?.as_str())?;
-
-                if row.len() != width {
-                    bail!(
-                        "incorrect row width '{}' on line {n}, expected '{width}'",
-                        row.len()
-                    );
-                }
-
-                Ok(row)
-            })
-            .collect::<Result<_>>()?;
-
-        let height = inner.len();
-
-        Ok(Self {
-            inner,
-            height,
-            width,
-        })
+        Parser::new(|character, _, _| T::try_from(character).map_err(Into::into)).read(reader)
     }
 }
 
@@ -260,3 +456,72 @@ impl<T: Display> Display for Map2D<T> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+fn grid(literal: &str) -> Map2D<char> {
+    Map2D::from_reader(literal.as_bytes()).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_remove() {
+        let mut map = grid("ab\ncd\n");
+
+        map.insert_row(1, '.');
+        map.insert_column(2, '.');
+        assert_eq!(map.to_string(), "ab.\n...\ncd.\n");
+        assert_eq!((map.width(), map.height()), (3, 3));
+
+        assert_eq!(map.remove_column(0), vec!['a', '.', 'c']);
+        assert_eq!(map.remove_row(1).to_string(), "..");
+        assert_eq!(map.to_string(), "b.\nd.\n");
+        assert_eq!((map.width(), map.height()), (2, 2));
+    }
+
+    #[test]
+    fn positions() {
+        let map = grid("ab\ncd\n");
+
+        assert_eq!(map.find(|cell| *cell > 'b'), Some((0, 1)));
+        assert_eq!(map.positions_of(&'d').collect::<Vec<_>>(), vec![(1, 1)]);
+        assert_eq!(map.count(|cell| *cell != 'a'), 3);
+        assert_eq!(map.iter_with_positions().nth(1), Some(((1, 0), &'b')));
+    }
+
+    #[test]
+    fn from_fn_and_map() {
+        #[derive(Debug, PartialEq)]
+        struct Cell(usize);
+
+        let map = Map2D::from_fn(2, 3, |x, y| Cell(x + 10 * y));
+        assert_eq!(map.get(2, 1), Some(&Cell(12)));
+
+        let digits = map.map(|Cell(value)| char::from_digit(*value as u32 % 10, 10).unwrap());
+        assert_eq!(digits.to_string(), "012\n012\n");
+    }
+
+    #[test]
+    fn transforms() {
+        let map = grid("abc\ndef\n");
+
+        assert_eq!(map.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(map.rotate_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(map.flip_horizontal().to_string(), "cba\nfed\n");
+    }
+
+    #[test]
+    fn pad_crop() {
+        let mut map = grid("ab\ncd\n");
+
+        map.pad(1, '.');
+        assert_eq!(map.to_string(), "....\n.ab.\n.cd.\n....\n");
+        assert_eq!((map.width(), map.height()), (4, 4));
+
+        map.crop(Rect::new(1, 1, 2, 1));
+        assert_eq!(map.to_string(), "ab\n");
+        assert_eq!((map.width(), map.height()), (2, 1));
+    }
+}
@@ -0,0 +1,179 @@
+use std::marker::PhantomData;
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+pub trait Packable: Copy {
+    const BITS: usize;
+
+    fn to_bits(self) -> u64;
+    fn from_bits(bits: u64) -> Self;
+}
+
+impl Packable for bool {
+    const BITS: usize = 1;
+
+    fn to_bits(self) -> u64 {
+        self as u64
+    }
+    fn from_bits(bits: u64) -> Self {
+        bits != 0
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct PackedMap2D<T> {
+    words: Box<[u64]>,
+    words_per_row: usize,
+    height: usize,
+    width: usize,
+    marker: PhantomData<T>,
+}
+
+impl<T: Packable> PackedMap2D<T> {
+    const CELLS_PER_WORD: usize = WORD_BITS / T::BITS;
+    const CELL_MASK: u64 = u64::MAX >> (WORD_BITS - T::BITS);
+
+    pub fn filled(height: usize, width: usize, value: T) -> Self {
+        assert!(
+            (1..=8).contains(&T::BITS),
+            "unsupported cell width '{}'",
+            T::BITS
+        );
+
+        let words_per_row = width.div_ceil(Self::CELLS_PER_WORD);
+        let mut map = Self {
+            words: vec![0; words_per_row * height].into_boxed_slice(),
+            words_per_row,
+            height,
+            width,
+            marker: PhantomData,
+        };
+        map.fill(value);
+        map
+    }
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    pub fn get(&self, x: usize, y: usize) -> Option<T> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        let (index, shift) = self.locate(x, y);
+        Some(T::from_bits((self.words[index] >> shift) & Self::CELL_MASK))
+    }
+    pub fn set(&mut self, x: usize, y: usize, value: T) {
+        assert!(
+            x < self.width && y < self.height,
+            "position ({x}, {y}) out of bounds for a {}x{} map",
+            self.width,
+            self.height
+        );
+        let (index, shift) = self.locate(x, y);
+        let word = &mut self.words[index];
+        *word &= !(Self::CELL_MASK << shift);
+        *word |= (value.to_bits() & Self::CELL_MASK) << shift;
+    }
+    pub fn fill(&mut self, value: T) {
+        for y in 0..self.height {
+            for x in 0..self.width {
+                self.set(x, y, value);
+            }
+        }
+    }
+    pub fn count(&self, value: T) -> usize {
+        if self.words.is_empty() {
+            return 0;
+        }
+        let fields =
+            |count: usize| -> u64 { (0..count).fold(0, |mask, n| mask | (1 << (n * T::BITS))) };
+        let pattern = fields(Self::CELLS_PER_WORD) * (value.to_bits() & Self::CELL_MASK);
+        let low = fields(Self::CELLS_PER_WORD) * (Self::CELL_MASK >> 1);
+        let high = fields(Self::CELLS_PER_WORD) << (T::BITS - 1);
+
+        let tail = self.width % Self::CELLS_PER_WORD;
+        let tail_mask = match tail {
+            0 => high,
+            _ => fields(tail) << (T::BITS - 1),
+        };
+
+        self.words
+            .chunks(self.words_per_row)
+            .map(|row| {
+                row.iter()
+                    .enumerate()
+                    .map(|(n, word)| {
+                        let valid = match n + 1 == row.len() {
+                            true => tail_mask,
+                            false => high,
+                        };
+                        // High bit of each field is set iff the field differs from `value`
+                        let diff = word ^ pattern;
+                        let nonzero = ((diff & low) + low) | diff;
+                        (valid.count_ones() - (nonzero & valid).count_ones()) as usize
+                    })
+                    .sum::<usize>()
+            })
+            .sum()
+    }
+    fn locate(&self, x: usize, y: usize) -> (usize, usize) {
+        let index = y * self.words_per_row + x / Self::CELLS_PER_WORD;
+        (index, (x % Self::CELLS_PER_WORD) * T::BITS)
+    }
+}
+
+impl<T: Packable + Default> PackedMap2D<T> {
+    pub fn new(height: usize, width: usize) -> Self {
+        Self::filled(height, width, T::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+    enum Tile {
+        #[default]
+        Empty,
+        Wall,
+        Water,
+    }
+
+    impl Packable for Tile {
+        const BITS: usize = 2;
+
+        fn to_bits(self) -> u64 {
+            self as u64
+        }
+        fn from_bits(bits: u64) -> Self {
+            match bits {
+                1 => Tile::Wall,
+                2 => Tile::Water,
+                _ => Tile::Empty,
+            }
+        }
+    }
+
+    #[test]
+    fn set_get_count() {
+        let mut map: PackedMap2D<Tile> = PackedMap2D::new(3, 35);
+        map.set(0, 0, Tile::Wall);
+        map.set(34, 2, Tile::Wall);
+        map.set(32, 1, Tile::Water);
+        map.set(0, 0, Tile::Water);
+
+        assert_eq!(map.get(0, 0), Some(Tile::Water));
+        assert_eq!(map.get(34, 2), Some(Tile::Wall));
+        assert_eq!(map.get(35, 2), None);
+        assert_eq!(map.count(Tile::Water), 2);
+        assert_eq!(map.count(Tile::Wall), 1);
+        assert_eq!(map.count(Tile::Empty), 3 * 35 - 3);
+
+        let bools = PackedMap2D::filled(2, 65, true);
+        assert_eq!(bools.count(true), 130);
+        assert_eq!(bools.count(false), 0);
+    }
+}
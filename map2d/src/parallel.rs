@@ -0,0 +1,79 @@
+use crate::{Map2D, Row};
+use rayon::prelude::*;
+
+pub use rayon::prelude;
+
+impl<T: Sync> Map2D<T> {
+    pub fn par_rows(&self) -> impl IndexedParallelIterator<Item = &Row<T>> {
+        self.inner.par_iter()
+    }
+    pub fn par_iter_with_positions(&self) -> impl ParallelIterator<Item = ((usize, usize), &T)> {
+        self.par_rows()
+            .enumerate()
+            .flat_map_iter(|(y, row)| row.iter().enumerate().map(move |(x, cell)| ((x, y), cell)))
+    }
+    pub fn par_map<U, F>(&self, f: F) -> Map2D<U>
+    where
+        U: Send,
+        F: Fn(&T) -> U + Sync,
+    {
+        Map2D {
+            inner: self
+                .par_rows()
+                .map(|row| row.iter().map(&f).collect())
+                .collect(),
+            height: self.height,
+            width: self.width,
+        }
+    }
+    pub fn par_map_with_positions<U, F>(&self, f: F) -> Map2D<U>
+    where
+        U: Send,
+        F: Fn((usize, usize), &T) -> U + Sync,
+    {
+        Map2D {
+            inner: self
+                .par_rows()
+                .enumerate()
+                .map(|(y, row)| {
+                    row.iter()
+                        .enumerate()
+                        .map(|(x, cell)| f((x, y), cell))
+                        .collect()
+                })
+                .collect(),
+            height: self.height,
+            width: self.width,
+        }
+    }
+}
+
+impl<T: Send> Map2D<T> {
+    pub fn par_rows_mut(&mut self) -> impl IndexedParallelIterator<Item = &mut Row<T>> {
+        self.inner.par_iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parallel() {
+        let mut map = Map2D::from_fn(40, 30, |x, y| x * y);
+
+        let squares = map.par_map(|cell| cell * cell);
+        assert_eq!(squares, map.map(|cell| cell * cell));
+        assert_eq!(
+            map.par_map_with_positions(|(x, y), cell| x * y == *cell),
+            Map2D::from_fn(40, 30, |_, _| true)
+        );
+
+        let sum: usize = map.par_iter_with_positions().map(|(_, cell)| cell).sum();
+        assert_eq!(sum, (0..30).sum::<usize>() * (0..40).sum::<usize>());
+
+        map.par_rows_mut()
+            .for_each(|row| row.iter_mut().for_each(|cell| *cell += 1));
+        assert!(map.par_rows().all(|row| row.iter().all(|cell| *cell > 0)));
+    }
+}
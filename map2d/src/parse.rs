@@ -0,0 +1,152 @@
+use crate::{Map2D, Row};
+use anyhow::{anyhow, bail, Context, Result};
+use std::io::{BufRead, BufReader, Read};
+use std::mem;
+
+pub struct Parser<'a, T> {
+    convert: Box<dyn FnMut(char, usize, usize) -> Result<T> + 'a>,
+    padding: Option<Box<dyn Fn() -> T + 'a>>,
+    crlf: bool,
+}
+
+impl<'a, T> Parser<'a, T> {
+    pub fn new<F>(convert: F) -> Self
+    where
+        F: FnMut(char, usize, usize) -> Result<T> + 'a,
+    {
+        Self {
+            convert: Box::new(convert),
+            padding: None,
+            crlf: true,
+        }
+    }
+    pub fn pad_with(mut self, value: T) -> Self
+    where
+        T: Clone + 'a,
+    {
+        self.padding = Some(Box::new(move || value.clone()));
+        self
+    }
+    pub fn crlf(mut self, crlf: bool) -> Self {
+        self.crlf = crlf;
+        self
+    }
+    pub fn read<R: Read>(&mut self, reader: R) -> Result<Map2D<T>> {
+        let mut grids = self.read_all(reader)?.into_iter();
+
+        let grid = grids.next().ok_or(anyhow!("no line in reader"))?;
+        if grids.next().is_some() {
+            bail!("expected a single grid, found several separated by blank lines");
+        }
+        Ok(grid)
+    }
+    pub fn read_all<R: Read>(&mut self, reader: R) -> Result<Vec<Map2D<T>>> {
+        let mut grids = vec![];
+        let mut rows = vec![];
+        let mut first_line = 0;
+
+        for (n, maybe_line) in BufReader::new(reader).split(b'\n').enumerate() {
+            let bytes = maybe_line.map_err(|error| anyhow!("error reading line: {error}"))?;
+            let mut line = String::from_utf8(bytes).with_context(|| format!("on line {n}"))?;
+            if self.crlf && line.ends_with('\r') {
+                line.pop();
+            }
+
+            if line.is_empty() {
+                if !rows.is_empty() {
+                    grids.push(self.finish(mem::take(&mut rows), first_line)?);
+                }
+                continue;
+            }
+            if rows.is_empty() {
+                first_line = n;
+            }
+
+            let y = rows.len();
+            let row = line
+                .chars()
+                .enumerate()
+                .map(|(x, character)| {
+                    (self.convert)(character, x, y)
+                        .with_context(|| format!("on line {n}, column {x}"))
+                })
+                .collect::<Result<Vec<T>>>()?;
+            rows.push(row);
+        }
+        if !rows.is_empty() {
+            grids.push(self.finish(rows, first_line)?);
+        }
+        Ok(grids)
+    }
+    fn finish(&self, rows: Vec<Vec<T>>, first_line: usize) -> Result<Map2D<T>> {
+        let width = match self.padding {
+            Some(_) => rows.iter().map(Vec::len).max().unwrap_or(0),
+            None => rows[0].len(),
+        };
+
+        let rows = rows
+            .into_iter()
+            .enumerate()
+            .map(|(y, mut row)| {
+                if row.len() != width {
+                    let Some(padding) = self.padding.as_ref() else {
+                        bail!(
+                            "incorrect row width '{}' on line {}, expected '{width}'",
+                            row.len(),
+                            first_line + y
+                        );
+                    };
+                    row.resize_with(width, padding);
+                }
+                Ok(Row::from(row))
+            })
+            .collect::<Result<_>>()?;
+
+        Map2D::from_rows(rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crlf_and_trailing_lines() -> Result<()> {
+        let map: Map2D<char> = Map2D::from_reader("äb\r\ncd\r\n\r\n\n".as_bytes())?;
+        assert_eq!(map.to_string(), "äb\ncd\n");
+
+        let raw = Parser::new(|c, _, _| Ok(c))
+            .crlf(false)
+            .read("ab\r\ncd\r\n".as_bytes())?;
+        assert_eq!(raw.width(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn positions_and_padding() -> Result<()> {
+        let mut start = None;
+        let map = Parser::new(|c, x, y| {
+            if c == 'S' {
+                start = Some((x, y));
+            }
+            Ok(c)
+        })
+        .pad_with('.')
+        .read("#\n#.S\n".as_bytes())?;
+
+        assert_eq!(map.to_string(), "#..\n#.S\n");
+        assert_eq!(start, Some((2, 1)));
+        Ok(())
+    }
+
+    #[test]
+    fn multiple_grids() -> Result<()> {
+        let input = "#.\n.#\n\n###\n\n\n..\n";
+        let grids = Parser::new(|c, _, _| Ok(c)).read_all(input.as_bytes())?;
+
+        let widths: Vec<_> = grids.iter().map(|grid| grid.width()).collect();
+        assert_eq!(widths, vec![2, 3, 2]);
+        assert!(Parser::new(|c, _, _| Ok(c)).read(input.as_bytes()).is_err());
+        Ok(())
+    }
+}
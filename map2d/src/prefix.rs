@@ -0,0 +1,128 @@
+use crate::{Map2D, Rect};
+use std::ops::Range;
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct PrefixSum {
+    sums: Box<[i64]>,
+}
+
+impl Default for PrefixSum {
+    fn default() -> Self {
+        Self {
+            sums: Box::new([0]),
+        }
+    }
+}
+
+impl PrefixSum {
+    pub fn len(&self) -> usize {
+        self.sums.len() - 1
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn sum(&self, range: Range<usize>) -> i64 {
+        if range.start >= range.end {
+            return 0;
+        }
+        self.sums[range.end] - self.sums[range.start]
+    }
+    pub fn total(&self) -> i64 {
+        self.sums[self.len()]
+    }
+}
+
+impl FromIterator<i64> for PrefixSum {
+    fn from_iter<I: IntoIterator<Item = i64>>(iter: I) -> Self {
+        let sums = [0]
+            .into_iter()
+            .chain(iter.into_iter().scan(0, |sum, weight| {
+                *sum += weight;
+                Some(*sum)
+            }))
+            .collect();
+        Self { sums }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct SummedAreaTable {
+    sums: Map2D<i64>,
+}
+
+impl SummedAreaTable {
+    pub fn new<T, F>(map: &Map2D<T>, mut weight: F) -> Self
+    where
+        F: FnMut(&T) -> i64,
+    {
+        let mut sums: Map2D<i64> = Map2D::new(map.height() + 1, map.width() + 1);
+
+        for ((x, y), cell) in map.iter_with_positions() {
+            sums[y + 1][x + 1] = weight(cell) + sums[y][x + 1] + sums[y + 1][x] - sums[y][x];
+        }
+        Self { sums }
+    }
+    pub fn sum(&self, rect: Rect) -> i64 {
+        if rect.width == 0 || rect.height == 0 {
+            return 0;
+        }
+        let (left, top, right, bottom) = (rect.x, rect.y, rect.right(), rect.bottom());
+        self.sums[bottom][right] - self.sums[top][right] - self.sums[bottom][left]
+            + self.sums[top][left]
+    }
+    pub fn row_span(&self, y: usize, x: Range<usize>) -> i64 {
+        self.sum(Rect::new(x.start, y, x.len(), 1))
+    }
+    pub fn column_span(&self, x: usize, y: Range<usize>) -> i64 {
+        self.sum(Rect::new(x, y.start, 1, y.len()))
+    }
+}
+
+impl<T> Map2D<T> {
+    pub fn row_prefix_sums<F>(&self, mut weight: F) -> Vec<PrefixSum>
+    where
+        F: FnMut(&T) -> i64,
+    {
+        self.rows()
+            .map(|row| row.iter().map(&mut weight).collect())
+            .collect()
+    }
+    pub fn column_prefix_sums<F>(&self, mut weight: F) -> Vec<PrefixSum>
+    where
+        F: FnMut(&T) -> i64,
+    {
+        self.columns()
+            .map(|column| column.map(&mut weight).collect())
+            .collect()
+    }
+    pub fn summed_area_table<F>(&self, weight: F) -> SummedAreaTable
+    where
+        F: FnMut(&T) -> i64,
+    {
+        SummedAreaTable::new(self, weight)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spans_and_rectangles() {
+        let map = Map2D::from_fn(3, 4, |x, y| (x + 4 * y) as i64);
+
+        let rows = map.row_prefix_sums(|cell| *cell);
+        let columns = map.column_prefix_sums(|cell| *cell);
+        assert_eq!(rows[1].sum(1..3), 5 + 6);
+        assert_eq!(columns[3].total(), 3 + 7 + 11);
+        assert_eq!(rows[0].sum(2..2), 0);
+        assert!(PrefixSum::default().is_empty());
+        assert_eq!(PrefixSum::default().total(), 0);
+
+        let table = map.summed_area_table(|cell| *cell);
+        assert_eq!(table.sum(Rect::new(0, 0, 4, 3)), (0..12).sum::<i64>());
+        assert_eq!(table.sum(Rect::new(1, 1, 2, 2)), 5 + 6 + 9 + 10);
+        assert_eq!(table.row_span(2, 1..4), 9 + 10 + 11);
+        assert_eq!(table.column_span(0, 0..2), 4);
+    }
+}
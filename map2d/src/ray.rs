@@ -0,0 +1,230 @@
+use crate::Map2D;
+
+pub use crate::Direction;
+
+#[derive(Debug, Clone)]
+pub struct Ray<'a, T> {
+    map: &'a Map2D<T>,
+    x: i64,
+    y: i64,
+    dx: i64,
+    dy: i64,
+}
+
+impl<'a, T> Ray<'a, T> {
+    pub fn new(map: &'a Map2D<T>, x: usize, y: usize, (dx, dy): (i64, i64)) -> Self {
+        assert!(dx != 0 || dy != 0, "ray step must be non-zero");
+        Self {
+            map,
+            x: x as i64,
+            y: y as i64,
+            dx,
+            dy,
+        }
+    }
+}
+
+impl<'a, T> Iterator for Ray<'a, T> {
+    type Item = ((usize, usize), &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let x = usize::try_from(self.x + self.dx).ok()?;
+        let y = usize::try_from(self.y + self.dy).ok()?;
+        let cell = self.map.get(x, y)?;
+        self.x += self.dx;
+        self.y += self.dy;
+        Some(((x, y), cell))
+    }
+}
+
+impl<T> Map2D<T> {
+    pub fn ray(&self, x: usize, y: usize, direction: Direction) -> Ray<'_, T> {
+        Ray::new(self, x, y, direction.offset())
+    }
+    pub fn cast<P>(
+        &self,
+        x: usize,
+        y: usize,
+        direction: Direction,
+        mut predicate: P,
+    ) -> Option<(usize, usize)>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.ray(x, y, direction)
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Line {
+    x: i64,
+    y: i64,
+    end: (i64, i64),
+    dx: i64,
+    dy: i64,
+    sx: i64,
+    sy: i64,
+    error: i64,
+    done: bool,
+}
+
+impl Iterator for Line {
+    type Item = (i64, i64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let point = (self.x, self.y);
+        if point == self.end {
+            self.done = true;
+            return Some(point);
+        }
+
+        let doubled = 2 * self.error;
+        if doubled >= self.dy {
+            self.error += self.dy;
+            self.x += self.sx;
+        }
+        if doubled <= self.dx {
+            self.error += self.dx;
+            self.y += self.sy;
+        }
+        Some(point)
+    }
+}
+
+pub fn line(from: (i64, i64), to: (i64, i64)) -> Line {
+    let dx = (to.0 - from.0).abs();
+    let dy = -(to.1 - from.1).abs();
+    Line {
+        x: from.0,
+        y: from.1,
+        end: to,
+        dx,
+        dy,
+        sx: (to.0 - from.0).signum(),
+        sy: (to.1 - from.1).signum(),
+        error: dx + dy,
+        done: false,
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct JumpTable {
+    up: Map2D<Option<usize>>,
+    down: Map2D<Option<usize>>,
+    left: Map2D<Option<usize>>,
+    right: Map2D<Option<usize>>,
+}
+
+impl JumpTable {
+    pub fn new<T, P>(map: &Map2D<T>, mut is_obstacle: P) -> Self
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let obstacles = map.map(|cell| is_obstacle(cell));
+        let (height, width) = (map.height(), map.width());
+
+        let mut table = Self {
+            up: Map2D::new(height, width),
+            down: Map2D::new(height, width),
+            left: Map2D::new(height, width),
+            right: Map2D::new(height, width),
+        };
+
+        for y in 0..height {
+            let mut next = None;
+            for x in 0..width {
+                table.left[y][x] = next;
+                next = obstacles[y][x].then_some(x).or(next);
+            }
+            let mut next = None;
+            for x in (0..width).rev() {
+                table.right[y][x] = next;
+                next = obstacles[y][x].then_some(x).or(next);
+            }
+        }
+        for x in 0..width {
+            let mut next = None;
+            for y in 0..height {
+                table.up[y][x] = next;
+                next = obstacles[y][x].then_some(y).or(next);
+            }
+            let mut next = None;
+            for y in (0..height).rev() {
+                table.down[y][x] = next;
+                next = obstacles[y][x].then_some(y).or(next);
+            }
+        }
+        table
+    }
+    pub fn next_obstacle(
+        &self,
+        x: usize,
+        y: usize,
+        direction: Direction,
+    ) -> Option<(usize, usize)> {
+        match direction {
+            Direction::Up => self.up.get(x, y)?.map(|y| (x, y)),
+            Direction::Down => self.down.get(x, y)?.map(|y| (x, y)),
+            Direction::Left => self.left.get(x, y)?.map(|x| (x, y)),
+            Direction::Right => self.right.get(x, y)?.map(|x| (x, y)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid;
+
+    fn example() -> Map2D<char> {
+        grid("..#..\n.....\n#...#\n..#..\n")
+    }
+
+    #[test]
+    fn rays() {
+        let map = example();
+
+        let cells: String = map.ray(2, 3, Direction::Up).map(|(_, cell)| cell).collect();
+        assert_eq!(cells, "..#");
+        assert_eq!(map.ray(0, 0, Direction::Left).count(), 0);
+        assert_eq!(
+            map.cast(1, 2, Direction::Right, |cell| *cell == '#'),
+            Some((4, 2))
+        );
+        assert_eq!(map.cast(1, 1, Direction::Down, |cell| *cell == '#'), None);
+    }
+
+    #[test]
+    fn jump_table_matches_cast() {
+        let map = example();
+        let table = JumpTable::new(&map, |cell| *cell == '#');
+
+        for ((x, y), _) in map.iter_with_positions() {
+            for direction in Direction::ALL {
+                assert_eq!(
+                    table.next_obstacle(x, y, direction),
+                    map.cast(x, y, direction, |cell| *cell == '#'),
+                    "({x}, {y}) {direction:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn bresenham() {
+        assert_eq!(
+            line((0, 0), (4, 2)).collect::<Vec<_>>(),
+            vec![(0, 0), (1, 1), (2, 1), (3, 2), (4, 2)]
+        );
+        assert_eq!(
+            line((2, 2), (2, -1)).collect::<Vec<_>>(),
+            vec![(2, 2), (2, 1), (2, 0), (2, -1)]
+        );
+        assert_eq!(line((3, 3), (3, 3)).count(), 1);
+    }
+}
@@ -0,0 +1,207 @@
+use crate::image::{Image, Rgb};
+use crate::Map2D;
+use anyhow::Result;
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::path::Path;
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+pub struct Recorder {
+    frames: Vec<Image>,
+    scale: usize,
+    delay: Duration,
+    every: usize,
+    captured: usize,
+}
+
+impl Recorder {
+    pub fn new(scale: usize) -> Self {
+        Self {
+            frames: vec![],
+            scale,
+            delay: Duration::from_millis(100),
+            every: 1,
+            captured: 0,
+        }
+    }
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+    pub fn every(mut self, every: usize) -> Self {
+        assert!(every > 0, "frame interval must be positive");
+        self.every = every;
+        self
+    }
+    pub fn frames(&self) -> &[Image] {
+        &self.frames
+    }
+    pub fn capture<T, F>(&mut self, map: &Map2D<T>, color: F)
+    where
+        F: Fn(&T) -> Rgb,
+    {
+        if self.should_capture() {
+            self.frames.push(Image::from_map(map, self.scale, color));
+        }
+    }
+    pub fn capture_image(&mut self, image: Image) {
+        if self.should_capture() {
+            self.frames.push(image);
+        }
+    }
+    pub fn write_frames<P: AsRef<Path>>(&self, directory: P, prefix: &str) -> Result<()> {
+        let directory = directory.as_ref();
+        fs::create_dir_all(directory)?;
+
+        for (n, frame) in self.frames.iter().enumerate() {
+            let file = File::create(directory.join(format!("{prefix}{n:05}.ppm")))?;
+            frame.write_ppm(BufWriter::new(file))?;
+        }
+        Ok(())
+    }
+    #[cfg(feature = "gif")]
+    pub fn write_gif<W: std::io::Write>(&self, writer: W) -> Result<()> {
+        use anyhow::anyhow;
+
+        let Some(first) = self.frames.first() else {
+            anyhow::bail!("no frames recorded");
+        };
+        let width = u16::try_from(first.width())?;
+        let height = u16::try_from(first.height())?;
+        let delay = u16::try_from(self.delay.as_millis() / 10)?;
+
+        let mut encoder = gif::Encoder::new(writer, width, height, &[])?;
+        encoder.set_repeat(gif::Repeat::Infinite)?;
+
+        let mut previous: Option<&Image> = None;
+        let mut pending: Option<gif::Frame> = None;
+
+        for image in self.frames.iter() {
+            if image.width() != first.width() || image.height() != first.height() {
+                return Err(anyhow!("frame dimensions differ from the first frame"));
+            }
+            let region = match previous {
+                Some(previous) => match changed_region(previous, image) {
+                    Some(region) => region,
+                    None => {
+                        // Identical frame, hold the pending one for longer instead
+                        if let Some(frame) = pending.as_mut() {
+                            frame.delay = frame.delay.saturating_add(delay);
+                        }
+                        continue;
+                    }
+                },
+                None => (0, 0, image.width(), image.height()),
+            };
+            if let Some(frame) = pending.take() {
+                encoder.write_frame(&frame)?;
+            }
+            pending = Some(encode_region(image, region, delay));
+            previous = Some(image);
+        }
+        if let Some(frame) = pending {
+            encoder.write_frame(&frame)?;
+        }
+        std::io::Write::flush(&mut encoder.into_inner()?)?;
+        Ok(())
+    }
+    fn should_capture(&mut self) -> bool {
+        let capture = self.captured.is_multiple_of(self.every);
+        self.captured += 1;
+        capture
+    }
+}
+
+#[cfg(feature = "gif")]
+fn changed_region(a: &Image, b: &Image) -> Option<(usize, usize, usize, usize)> {
+    let mut bounds: Option<(usize, usize, usize, usize)> = None;
+
+    let rows = a
+        .pixels()
+        .chunks(a.width() * 3)
+        .zip(b.pixels().chunks(b.width() * 3));
+
+    for (y, (row_a, row_b)) in rows.enumerate() {
+        for (x, (pixel_a, pixel_b)) in row_a.chunks(3).zip(row_b.chunks(3)).enumerate() {
+            if pixel_a == pixel_b {
+                continue;
+            }
+            bounds = Some(match bounds {
+                None => (x, y, x + 1, y + 1),
+                Some((min_x, min_y, max_x, max_y)) => (
+                    min_x.min(x),
+                    min_y.min(y),
+                    max_x.max(x + 1),
+                    max_y.max(y + 1),
+                ),
+            });
+        }
+    }
+
+    bounds.map(|(min_x, min_y, max_x, max_y)| (min_x, min_y, max_x - min_x, max_y - min_y))
+}
+
+#[cfg(feature = "gif")]
+fn encode_region(
+    image: &Image,
+    (left, top, width, height): (usize, usize, usize, usize),
+    delay: u16,
+) -> gif::Frame<'static> {
+    let pixels: Vec<u8> = image
+        .pixels()
+        .chunks(image.width() * 3)
+        .skip(top)
+        .take(height)
+        .flat_map(|row| &row[left * 3..(left + width) * 3])
+        .copied()
+        .collect();
+
+    let mut frame = gif::Frame::from_rgb_speed(width as u16, height as u16, &pixels, 10);
+    frame.left = left as u16;
+    frame.top = top as u16;
+    frame.delay = delay;
+    frame.dispose = gif::DisposalMethod::Keep;
+    frame
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn color(cell: &bool) -> Rgb {
+        match cell {
+            true => [255, 255, 255],
+            false => [0, 0, 0],
+        }
+    }
+
+    #[test]
+    fn every_nth_frame() {
+        let mut map: Map2D<bool> = Map2D::new(2, 2);
+        let mut recorder = Recorder::new(1).every(2);
+
+        for n in 0..5 {
+            map[n % 2][n / 2 % 2] = true;
+            recorder.capture(&map, color);
+        }
+        assert_eq!(recorder.frames().len(), 3);
+    }
+
+    #[cfg(feature = "gif")]
+    #[test]
+    fn gif() -> Result<()> {
+        let mut map: Map2D<bool> = Map2D::new(4, 4);
+        let mut recorder = Recorder::new(2).delay(Duration::from_millis(50));
+
+        recorder.capture(&map, color);
+        recorder.capture(&map, color);
+        map[1][2] = true;
+        recorder.capture(&map, color);
+
+        let mut out = vec![];
+        recorder.write_gif(&mut out)?;
+        assert!(out.starts_with(b"GIF89a"));
+        Ok(())
+    }
+}
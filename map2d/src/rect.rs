@@ -0,0 +1,27 @@
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub struct Rect {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Rect {
+    pub fn new(x: usize, y: usize, width: usize, height: usize) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+    pub fn right(&self) -> usize {
+        self.x + self.width
+    }
+    pub fn bottom(&self) -> usize {
+        self.y + self.height
+    }
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        (self.x..self.right()).contains(&x) && (self.y..self.bottom()).contains(&y)
+    }
+}
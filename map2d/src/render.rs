@@ -0,0 +1,299 @@
+use crate::Map2D;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{self, Display, Write};
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    pub const PALETTE: [Color; 6] = [
+        Color::Red,
+        Color::Green,
+        Color::Yellow,
+        Color::Blue,
+        Color::Magenta,
+        Color::Cyan,
+    ];
+
+    fn code(self, background: bool) -> String {
+        let base = if background { 40 } else { 30 };
+        match self {
+            Color::Black => base.to_string(),
+            Color::Red => (base + 1).to_string(),
+            Color::Green => (base + 2).to_string(),
+            Color::Yellow => (base + 3).to_string(),
+            Color::Blue => (base + 4).to_string(),
+            Color::Magenta => (base + 5).to_string(),
+            Color::Cyan => (base + 6).to_string(),
+            Color::White => (base + 7).to_string(),
+            Color::Rgb(r, g, b) => format!("{};2;{r};{g};{b}", base + 8),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub struct Style {
+    pub foreground: Option<Color>,
+    pub background: Option<Color>,
+    pub bold: bool,
+    pub character: Option<char>,
+}
+
+impl Style {
+    pub fn foreground(self, color: Color) -> Self {
+        Self {
+            foreground: Some(color),
+            ..self
+        }
+    }
+    pub fn background(self, color: Color) -> Self {
+        Self {
+            background: Some(color),
+            ..self
+        }
+    }
+    pub fn bold(self) -> Self {
+        Self { bold: true, ..self }
+    }
+    pub fn character(self, character: char) -> Self {
+        Self {
+            character: Some(character),
+            ..self
+        }
+    }
+    fn merge(self, other: Style) -> Self {
+        Self {
+            foreground: other.foreground.or(self.foreground),
+            background: other.background.or(self.background),
+            bold: self.bold || other.bold,
+            character: other.character.or(self.character),
+        }
+    }
+    fn write_cell<T: Display>(&self, f: &mut fmt::Formatter<'_>, cell: &T) -> fmt::Result {
+        let codes: Vec<String> = self
+            .bold
+            .then(|| "1".to_string())
+            .into_iter()
+            .chain(self.foreground.map(|color| color.code(false)))
+            .chain(self.background.map(|color| color.code(true)))
+            .collect();
+
+        if !codes.is_empty() {
+            write!(f, "\x1b[{}m", codes.join(";"))?;
+        }
+        match self.character {
+            Some(character) => f.write_char(character)?,
+            None => cell.fmt(f)?,
+        }
+        if !codes.is_empty() {
+            f.write_str("\x1b[0m")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Overlay {
+    Points {
+        points: HashSet<(usize, usize)>,
+        style: Style,
+    },
+    Path {
+        steps: HashMap<(usize, usize), Option<char>>,
+        style: Style,
+    },
+    Labels {
+        labels: HashMap<(usize, usize), usize>,
+        palette: Vec<Color>,
+    },
+}
+
+impl Overlay {
+    pub fn points<I: IntoIterator<Item = (usize, usize)>>(points: I, style: Style) -> Self {
+        Overlay::Points {
+            points: points.into_iter().collect(),
+            style,
+        }
+    }
+    pub fn path<I: IntoIterator<Item = (usize, usize)>>(path: I, style: Style) -> Self {
+        Self::steps(path.into_iter().collect(), false, style)
+    }
+    pub fn cycle<I: IntoIterator<Item = (usize, usize)>>(cycle: I, style: Style) -> Self {
+        Self::steps(cycle.into_iter().collect(), true, style)
+    }
+    pub fn labels<I: IntoIterator<Item = ((usize, usize), usize)>>(labels: I) -> Self {
+        Overlay::Labels {
+            labels: labels.into_iter().collect(),
+            palette: Color::PALETTE.to_vec(),
+        }
+    }
+    fn steps(path: Vec<(usize, usize)>, closed: bool, style: Style) -> Self {
+        let steps = path
+            .iter()
+            .enumerate()
+            .map(|(n, from)| {
+                let to = path.get(n + 1).or(path.first().filter(|_| closed));
+                (*from, to.and_then(|to| arrow(*from, *to)))
+            })
+            .collect();
+        Overlay::Path { steps, style }
+    }
+    fn style_at(&self, x: usize, y: usize) -> Option<Style> {
+        match self {
+            Overlay::Points { points, style } => points.contains(&(x, y)).then_some(*style),
+            Overlay::Path { steps, style } => steps.get(&(x, y)).map(|arrow| match arrow {
+                Some(arrow) if style.character.is_none() => style.character(*arrow),
+                _ => *style,
+            }),
+            Overlay::Labels { labels, palette } => labels
+                .get(&(x, y))
+                .map(|label| Style::default().background(palette[label % palette.len()])),
+        }
+    }
+}
+
+fn arrow(from: (usize, usize), to: (usize, usize)) -> Option<char> {
+    Some(
+        match (to.0 as i64 - from.0 as i64, to.1 as i64 - from.1 as i64) {
+            (1, 0) => '>',
+            (-1, 0) => '<',
+            (0, 1) => 'v',
+            (0, -1) => '^',
+            _ => return None,
+        },
+    )
+}
+
+pub struct Renderer<'a, T> {
+    map: &'a Map2D<T>,
+    style: Box<dyn Fn(&T) -> Style + 'a>,
+    overlays: Vec<Overlay>,
+    rulers: bool,
+}
+
+impl<'a, T> Renderer<'a, T> {
+    pub fn new(map: &'a Map2D<T>) -> Self {
+        Self {
+            map,
+            style: Box::new(|_| Style::default()),
+            overlays: vec![],
+            rulers: false,
+        }
+    }
+    pub fn style<F: Fn(&T) -> Style + 'a>(mut self, style: F) -> Self {
+        self.style = Box::new(style);
+        self
+    }
+    pub fn overlay(mut self, overlay: Overlay) -> Self {
+        self.overlays.push(overlay);
+        self
+    }
+    pub fn rulers(mut self, rulers: bool) -> Self {
+        self.rulers = rulers;
+        self
+    }
+    fn write_rulers(&self, f: &mut fmt::Formatter<'_>, margin: usize) -> fmt::Result {
+        let digits = self.map.width().saturating_sub(1).to_string().len();
+
+        for place in (0..digits).rev() {
+            write!(f, "{:margin$} ", "")?;
+            for x in 0..self.map.width() {
+                match x / 10usize.pow(place as u32) {
+                    0 if place > 0 => f.write_char(' ')?,
+                    digit => write!(f, "{}", digit % 10)?,
+                }
+            }
+            f.write_char('\n')?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Display> Display for Renderer<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let margin = self.map.height().saturating_sub(1).to_string().len();
+
+        if self.rulers {
+            self.write_rulers(f, margin)?;
+        }
+        for (y, row) in self.map.rows().enumerate() {
+            if self.rulers {
+                write!(f, "{y:>margin$} ")?;
+            }
+            for (x, cell) in row.iter().enumerate() {
+                let style = self
+                    .overlays
+                    .iter()
+                    .filter_map(|overlay| overlay.style_at(x, y))
+                    .fold((self.style)(cell), Style::merge);
+                style.write_cell(f, cell)?;
+            }
+            f.write_char('\n')?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> Map2D<T> {
+    pub fn render(&self) -> Renderer<'_, T> {
+        Renderer::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid;
+
+    #[test]
+    fn plain_with_rulers() {
+        let map = grid("...........\n");
+        let rendered = map.render().rulers(true).to_string();
+        assert_eq!(rendered, "            1\n  01234567890\n0 ...........\n");
+    }
+
+    #[test]
+    fn overlays() {
+        let map = grid("...\n.#.\n");
+        let rendered = map
+            .render()
+            .style(|cell| match cell {
+                '#' => Style::default().bold(),
+                _ => Style::default(),
+            })
+            .overlay(Overlay::path([(0, 0), (1, 0), (2, 0)], Style::default()))
+            .overlay(Overlay::points(
+                [(2, 1)],
+                Style::default().foreground(Color::Red),
+            ))
+            .to_string();
+
+        assert_eq!(rendered, ">>.\n.\x1b[1m#\x1b[0m\x1b[31m.\x1b[0m\n");
+    }
+
+    #[test]
+    fn open_and_closed_paths() {
+        let map = Map2D::from_fn(2, 2, |_, _| '.');
+        let square = [(0, 0), (1, 0), (1, 1), (0, 1)];
+
+        let open = map
+            .render()
+            .overlay(Overlay::path(square, Style::default()));
+        assert_eq!(open.to_string(), ">v\n.<\n");
+
+        let closed = map
+            .render()
+            .overlay(Overlay::cycle(square, Style::default()));
+        assert_eq!(closed.to_string(), ">v\n^<\n");
+    }
+}
@@ -0,0 +1,60 @@
+use crate::{Direction, Grid};
+use std::collections::{HashMap, VecDeque};
+
+pub const NEIGHBORS: [(i64, i64); 4] = Direction::OFFSETS;
+
+pub fn neighbors(x: i64, y: i64) -> impl Iterator<Item = (i64, i64)> {
+    NEIGHBORS.iter().map(move |(dx, dy)| (x + dx, y + dy))
+}
+
+pub fn bfs<G, F>(
+    grid: &G,
+    start: (i64, i64),
+    max_distance: usize,
+    passable: F,
+) -> HashMap<(i64, i64), usize>
+where
+    G: Grid,
+    F: Fn(&G::Cell) -> bool,
+{
+    let mut distances = HashMap::from([(start, 0)]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some((x, y)) = queue.pop_front() {
+        let distance = distances[&(x, y)];
+        if distance >= max_distance {
+            continue;
+        }
+        for next in neighbors(x, y) {
+            if distances.contains_key(&next) {
+                continue;
+            }
+            if grid.cell(next.0, next.1).is_some_and(&passable) {
+                distances.insert(next, distance + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+    distances
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid;
+
+    #[test]
+    fn bfs_tiled() {
+        let map = grid("...\n.#.\n...\n");
+        let tiled = map.tiled();
+
+        let bounded = bfs(&map, (0, 0), 10, |cell| *cell == '.');
+        assert_eq!(bounded.len(), 8);
+
+        let unbounded = bfs(&tiled, (0, 0), 4, |cell| *cell == '.');
+        assert_eq!(unbounded[&(-1, -3)], 4);
+        assert!(unbounded.values().all(|distance| *distance <= 4));
+        assert!(!unbounded.contains_key(&(1, 1)));
+        assert!(!unbounded.contains_key(&(-2, 1)));
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{Map2D, Row};
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+
+impl<T: Serialize> Serialize for Row<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.inner.serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Row<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::deserialize(deserializer).map(Row::from)
+    }
+}
+
+impl<T: Serialize> Serialize for Map2D<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.inner.serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Map2D<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let rows = Vec::<Row<T>>::deserialize(deserializer)?;
+        Map2D::from_rows(rows).map_err(de::Error::custom)
+    }
+}
+
+pub mod as_strings {
+    use crate::{Map2D, Row};
+    use anyhow::Error;
+    use serde::de::{self, Deserialize, Deserializer};
+    use serde::ser::{Serialize, Serializer};
+
+    pub fn serialize<S, T>(map: &Map2D<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Copy + Into<char>,
+    {
+        let lines: Vec<String> = map
+            .rows()
+            .map(|row| row.iter().map(|cell| (*cell).into()).collect())
+            .collect();
+        lines.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<Map2D<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: TryFrom<char>,
+        <T as TryFrom<char>>::Error: Into<Error>,
+    {
+        let rows = Vec::<String>::deserialize(deserializer)?
+            .iter()
+            .map(|line| Row::try_from(line.as_str()))
+            .collect::<anyhow::Result<Vec<_>>>()
+            .map_err(de::Error::custom)?;
+        Map2D::from_rows(rows).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, serde::Serialize, serde::Deserialize)]
+    struct Snapshot {
+        #[serde(with = "as_strings")]
+        text: Map2D<char>,
+        numbers: Map2D<u8>,
+    }
+
+    #[test]
+    fn round_trip() -> anyhow::Result<()> {
+        let json = r#"{"text":["ab","cd"],"numbers":[[1,2,3]]}"#;
+        let snapshot: Snapshot = serde_json::from_str(json)?;
+
+        assert_eq!(snapshot.text.to_string(), "ab\ncd\n");
+        assert_eq!(snapshot.numbers.width(), 3);
+        assert_eq!(serde_json::to_string(&snapshot)?, json);
+
+        assert!(serde_json::from_str::<Map2D<u8>>("[[1,2],[3]]").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,170 @@
+use crate::{Bounds, Grid, GridMut, Map2D, Row};
+use std::collections::hash_map::{self, HashMap};
+use std::fmt::{self, Display, Write};
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SparseMap2D<T> {
+    inner: HashMap<(i64, i64), T>,
+    default: T,
+    bounds: Bounds,
+}
+
+impl<T> SparseMap2D<T> {
+    pub fn new(default: T) -> Self {
+        Self {
+            inner: HashMap::new(),
+            default,
+            bounds: Bounds::default(),
+        }
+    }
+    pub fn bounds(&self) -> Bounds {
+        self.bounds
+    }
+    pub fn height(&self) -> usize {
+        self.bounds.height()
+    }
+    pub fn width(&self) -> usize {
+        self.bounds.width()
+    }
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+    pub fn default_value(&self) -> &T {
+        &self.default
+    }
+    pub fn contains(&self, x: i64, y: i64) -> bool {
+        self.bounds.contains(x, y)
+    }
+    pub fn get(&self, x: i64, y: i64) -> &T {
+        self.inner.get(&(x, y)).unwrap_or(&self.default)
+    }
+    pub fn set(&mut self, x: i64, y: i64, value: T) -> Option<T> {
+        self.bounds.extend(x, y);
+        self.inner.insert((x, y), value)
+    }
+    pub fn remove(&mut self, x: i64, y: i64) -> Option<T> {
+        let value = self.inner.remove(&(x, y))?;
+
+        let on_edge = x == self.bounds.min_x
+            || x == self.bounds.max_x - 1
+            || y == self.bounds.min_y
+            || y == self.bounds.max_y - 1;
+
+        if on_edge {
+            self.bounds = self.inner.keys().copied().collect();
+        }
+        Some(value)
+    }
+    pub fn iter(&self) -> impl Iterator<Item = ((i64, i64), &T)> {
+        self.inner
+            .iter()
+            .map(|(position, value)| (*position, value))
+    }
+    pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let Bounds {
+            min_x,
+            min_y,
+            max_x,
+            max_y,
+        } = self.bounds;
+
+        (min_y..max_y).map(move |y| (min_x..max_x).map(move |x| self.get(x, y)))
+    }
+}
+
+impl<T: Clone> SparseMap2D<T> {
+    pub fn get_mut(&mut self, x: i64, y: i64) -> &mut T {
+        self.bounds.extend(x, y);
+        match self.inner.entry((x, y)) {
+            hash_map::Entry::Occupied(entry) => entry.into_mut(),
+            hash_map::Entry::Vacant(entry) => entry.insert(self.default.clone()),
+        }
+    }
+    pub fn to_dense(&self) -> Map2D<T> {
+        let inner = self
+            .rows()
+            .map(|row| row.cloned().collect::<Row<T>>())
+            .collect();
+
+        Map2D {
+            inner,
+            height: self.height(),
+            width: self.width(),
+        }
+    }
+}
+
+impl<T> Grid for SparseMap2D<T> {
+    type Cell = T;
+
+    fn bounds(&self) -> Bounds {
+        self.bounds
+    }
+    fn cell(&self, x: i64, y: i64) -> Option<&T> {
+        Some(self.get(x, y))
+    }
+}
+
+impl<T: Clone> GridMut for SparseMap2D<T> {
+    fn cell_mut(&mut self, x: i64, y: i64) -> Option<&mut T> {
+        Some(self.get_mut(x, y))
+    }
+}
+
+impl<T: Display> Display for SparseMap2D<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                cell.fmt(f)?;
+            }
+            f.write_char('\n')?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filled<G: Grid<Cell = char>>(grid: &G) -> usize {
+        grid.positions()
+            .filter(|&(x, y)| grid.cell(x, y) == Some(&'#'))
+            .count()
+    }
+
+    #[test]
+    fn bounds_and_display() {
+        let mut map = SparseMap2D::new('.');
+        map.set(-1, -2, '#');
+        map.set(1, 0, '#');
+
+        assert_eq!(map.bounds(), Bounds::new(-1, -2, 2, 1));
+        assert_eq!(map.to_string(), "#..\n...\n..#\n");
+        assert_eq!(map.get(0, -1), &'.');
+        assert_eq!(map.get(2, 0), &'.');
+        assert_eq!(map.get(-50, 70), &'.');
+        assert!(map.contains(0, -1));
+        assert!(!map.contains(2, 0));
+
+        map.remove(-1, -2);
+        assert_eq!(map.bounds(), Bounds::new(1, 0, 2, 1));
+        assert_eq!(map.to_string(), "#\n");
+    }
+
+    #[test]
+    fn grid_trait() {
+        let mut sparse = SparseMap2D::new('.');
+        *sparse.get_mut(-3, 4) = '#';
+        *sparse.get_mut(2, -1) = '#';
+
+        let dense = sparse.to_dense();
+        assert_eq!((dense.width(), dense.height()), (6, 6));
+        assert_eq!(filled(&sparse), 2);
+        assert_eq!(sparse.cell(100, -100), Some(&'.'));
+        assert_eq!(filled(&dense), 2);
+    }
+}
@@ -0,0 +1,121 @@
+use crate::Map2D;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Axis {
+    Vertical(usize),
+    Horizontal(usize),
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Rotation {
+    Quarter,
+    Half,
+    ThreeQuarter,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Reflection {
+    pub axis: Axis,
+    pub mismatches: Vec<((usize, usize), (usize, usize))>,
+}
+
+impl<T: PartialEq> Map2D<T> {
+    pub fn reflection_axes(&self) -> impl Iterator<Item = Axis> {
+        (1..self.width())
+            .map(Axis::Vertical)
+            .chain((1..self.height()).map(Axis::Horizontal))
+    }
+    pub fn reflection_pairs(
+        &self,
+        axis: Axis,
+    ) -> impl Iterator<Item = ((usize, usize), (usize, usize))> + '_ {
+        let (width, height) = (self.width(), self.height());
+        let (vertical, horizontal) = match axis {
+            Axis::Vertical(n) => (Some(n), None),
+            Axis::Horizontal(n) => (None, Some(n)),
+        };
+
+        let vertical = vertical.into_iter().flat_map(move |n| {
+            (0..height)
+                .flat_map(move |y| (0..n.min(width - n)).map(move |i| ((n - 1 - i, y), (n + i, y))))
+        });
+        let horizontal = horizontal.into_iter().flat_map(move |n| {
+            (0..n.min(height - n))
+                .flat_map(move |i| (0..width).map(move |x| ((x, n - 1 - i), (x, n + i))))
+        });
+        vertical.chain(horizontal)
+    }
+    pub fn mismatches(
+        &self,
+        axis: Axis,
+    ) -> impl Iterator<Item = ((usize, usize), (usize, usize))> + '_ {
+        self.reflection_pairs(axis)
+            .filter(|((ax, ay), (bx, by))| self[*ay][*ax] != self[*by][*bx])
+    }
+    pub fn reflections_with_mismatches(&self, k: usize) -> Vec<Reflection> {
+        self.reflection_axes()
+            .filter_map(|axis| {
+                let mismatches: Vec<_> = self.mismatches(axis).take(k + 1).collect();
+                (mismatches.len() == k).then_some(Reflection { axis, mismatches })
+            })
+            .collect()
+    }
+    pub fn reflections(&self) -> Vec<Axis> {
+        self.reflections_with_mismatches(0)
+            .into_iter()
+            .map(|reflection| reflection.axis)
+            .collect()
+    }
+    pub fn rotational_symmetries(&self) -> Vec<Rotation> {
+        let (width, height) = (self.width(), self.height());
+        let half = self
+            .iter_with_positions()
+            .all(|((x, y), cell)| *cell == self[height - 1 - y][width - 1 - x]);
+        let quarter = width == height
+            && self
+                .iter_with_positions()
+                .all(|((x, y), cell)| *cell == self[width - 1 - x][y]);
+
+        match (quarter, half) {
+            (true, _) => vec![Rotation::Quarter, Rotation::Half, Rotation::ThreeQuarter],
+            (false, true) => vec![Rotation::Half],
+            (false, false) => vec![],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid;
+
+    #[test]
+    fn reflections() {
+        let map = grid("#..#\n.##.\n");
+        assert_eq!(map.reflections(), vec![Axis::Vertical(2)]);
+
+        let almost = grid("#.\n#.\n##\n");
+        let reflections = almost.reflections_with_mismatches(1);
+        assert_eq!(
+            reflections,
+            vec![Reflection {
+                axis: Axis::Horizontal(2),
+                mismatches: vec![((1, 1), (1, 2))],
+            }]
+        );
+        assert_eq!(almost.reflections(), vec![Axis::Horizontal(1)]);
+    }
+
+    #[test]
+    fn rotations() {
+        assert_eq!(
+            grid("#.#\n...\n#.#\n").rotational_symmetries(),
+            vec![Rotation::Quarter, Rotation::Half, Rotation::ThreeQuarter]
+        );
+        assert_eq!(
+            grid("#..\n..#\n").rotational_symmetries(),
+            vec![Rotation::Half]
+        );
+        assert!(grid("#.\n..\n").rotational_symmetries().is_empty());
+    }
+}
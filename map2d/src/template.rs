@@ -0,0 +1,201 @@
+use crate::cycle::fingerprint;
+use crate::Map2D;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+const BASE: u64 = 0x100000001b3;
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub struct Orientation {
+    pub rotations: u8,
+    pub flipped: bool,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Match {
+    pub position: (usize, usize),
+    pub orientation: Orientation,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Template<T> {
+    cells: Map2D<Option<T>>,
+}
+
+#[derive(Debug)]
+struct Segment {
+    dx: usize,
+    dy: usize,
+    len: usize,
+    hash: u64,
+}
+
+impl<T: Clone + PartialEq> Template<T> {
+    pub fn new(cells: Map2D<Option<T>>) -> Self {
+        Self { cells }
+    }
+    pub fn from_map<F>(map: &Map2D<T>, mut is_wildcard: F) -> Self
+    where
+        F: FnMut(&T) -> bool,
+    {
+        Self::new(map.map(|cell| (!is_wildcard(cell)).then(|| cell.clone())))
+    }
+    pub fn width(&self) -> usize {
+        self.cells.width()
+    }
+    pub fn height(&self) -> usize {
+        self.cells.height()
+    }
+    pub fn matches_at(&self, map: &Map2D<T>, x: usize, y: usize) -> bool {
+        if x + self.width() > map.width() || y + self.height() > map.height() {
+            return false;
+        }
+        self.cells.iter_with_positions().all(|((dx, dy), cell)| {
+            cell.as_ref()
+                .is_none_or(|cell| *cell == map[y + dy][x + dx])
+        })
+    }
+    pub fn find(&self, map: &Map2D<T>) -> Vec<(usize, usize)> {
+        self.offsets(map)
+            .filter(|(x, y)| self.matches_at(map, *x, *y))
+            .collect()
+    }
+    pub fn orientations(&self) -> Vec<(Orientation, Template<T>)> {
+        let mut orientations: Vec<(Orientation, Template<T>)> = vec![];
+
+        for flipped in [false, true] {
+            let mut cells = match flipped {
+                true => self.cells.flip_horizontal(),
+                false => self.cells.clone(),
+            };
+            for rotations in 0..4 {
+                let template = Template::new(cells.clone());
+                if !orientations.iter().any(|(_, other)| *other == template) {
+                    orientations.push((Orientation { rotations, flipped }, template));
+                }
+                cells = cells.rotate_clockwise();
+            }
+        }
+        orientations
+    }
+    fn offsets(&self, map: &Map2D<T>) -> impl Iterator<Item = (usize, usize)> {
+        let columns = (map.width() + 1).saturating_sub(self.width());
+        let rows = (map.height() + 1).saturating_sub(self.height());
+        (0..rows).flat_map(move |y| (0..columns).map(move |x| (x, y)))
+    }
+}
+
+impl<T: Clone + Hash + Eq> Template<T> {
+    pub fn find_hashed(&self, map: &Map2D<T>) -> Vec<(usize, usize)> {
+        let segments = self.segments();
+
+        let mut windows: HashMap<usize, Vec<Vec<u64>>> = HashMap::new();
+        for segment in segments.iter() {
+            windows
+                .entry(segment.len)
+                .or_insert_with(|| window_hashes(map, segment.len));
+        }
+
+        self.offsets(map)
+            .filter(|(x, y)| {
+                segments.iter().all(|segment| {
+                    windows[&segment.len][y + segment.dy][x + segment.dx] == segment.hash
+                })
+            })
+            .filter(|(x, y)| self.matches_at(map, *x, *y))
+            .collect()
+    }
+    pub fn find_oriented(&self, map: &Map2D<T>) -> Vec<Match> {
+        self.orientations()
+            .into_iter()
+            .flat_map(|(orientation, template)| {
+                template
+                    .find_hashed(map)
+                    .into_iter()
+                    .map(move |position| Match {
+                        position,
+                        orientation,
+                    })
+            })
+            .collect()
+    }
+    fn segments(&self) -> Vec<Segment> {
+        let mut segments = vec![];
+
+        for (dy, row) in self.cells.rows().enumerate() {
+            let mut dx = 0;
+            while dx < row.len() {
+                let len = row[dx..].iter().take_while(|cell| cell.is_some()).count();
+                if len == 0 {
+                    dx += 1;
+                    continue;
+                }
+                let hash = row[dx..dx + len].iter().flatten().fold(0u64, |hash, cell| {
+                    hash.wrapping_mul(BASE).wrapping_add(fingerprint(cell))
+                });
+                segments.push(Segment { dx, dy, len, hash });
+                dx += len;
+            }
+        }
+        segments
+    }
+}
+
+fn window_hashes<T: Hash>(map: &Map2D<T>, len: usize) -> Vec<Vec<u64>> {
+    let leading = (1..len).fold(1u64, |power, _| power.wrapping_mul(BASE));
+
+    map.rows()
+        .map(|row| {
+            let cells: Vec<u64> = row.iter().map(fingerprint).collect();
+            if cells.len() < len {
+                return vec![];
+            }
+            let mut hash = cells[..len].iter().fold(0u64, |hash, cell| {
+                hash.wrapping_mul(BASE).wrapping_add(*cell)
+            });
+            let mut hashes = vec![hash];
+            for x in len..cells.len() {
+                hash = hash
+                    .wrapping_sub(cells[x - len].wrapping_mul(leading))
+                    .wrapping_mul(BASE)
+                    .wrapping_add(cells[x]);
+                hashes.push(hash);
+            }
+            hashes
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid;
+
+    fn template(literal: &str) -> Template<char> {
+        Template::from_map(&grid(literal), |cell| *cell == '?')
+    }
+
+    #[test]
+    fn wildcards() {
+        let map = grid("#.#.#..\n.#..#.#\n#.#..#.\n");
+        let shape = template("#?#\n?#?\n");
+
+        assert_eq!(shape.find(&map), vec![(0, 0), (4, 1)]);
+        assert_eq!(shape.find_hashed(&map), shape.find(&map));
+        assert!(template("##\n##\n##\n##\n").find_hashed(&map).is_empty());
+    }
+
+    #[test]
+    fn orientations() {
+        let map = grid("....\n.#..\n.##.\n....\n");
+        let corner = template("#?\n##\n");
+
+        assert_eq!(corner.orientations().len(), 4);
+        assert_eq!(corner.find_hashed(&map), vec![(1, 1)]);
+
+        let matches = template("##\n#?\n").find_oriented(&map);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].position, (1, 1));
+        assert_ne!(matches[0].orientation, Orientation::default());
+    }
+}
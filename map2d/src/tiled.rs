@@ -0,0 +1,76 @@
+use crate::{Bounds, Grid, Map2D};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Tiled<'a, T> {
+    map: &'a Map2D<T>,
+}
+
+impl<'a, T> Tiled<'a, T> {
+    pub fn new(map: &'a Map2D<T>) -> Self {
+        Self { map }
+    }
+    pub fn get(&self, x: i64, y: i64) -> Option<&'a T> {
+        let (x, y) = self.local(x, y)?;
+        self.map.get(x, y)
+    }
+    pub fn local(&self, x: i64, y: i64) -> Option<(usize, usize)> {
+        let (width, height) = self.dimensions()?;
+        Some((x.rem_euclid(width) as usize, y.rem_euclid(height) as usize))
+    }
+    pub fn tile(&self, x: i64, y: i64) -> Option<(i64, i64)> {
+        let (width, height) = self.dimensions()?;
+        Some((x.div_euclid(width), y.div_euclid(height)))
+    }
+    fn dimensions(&self) -> Option<(i64, i64)> {
+        if self.map.width() == 0 || self.map.height() == 0 {
+            return None;
+        }
+        Some((self.map.width() as i64, self.map.height() as i64))
+    }
+}
+
+impl<T> Grid for Tiled<'_, T> {
+    type Cell = T;
+
+    fn bounds(&self) -> Bounds {
+        self.map.bounds()
+    }
+    fn cell(&self, x: i64, y: i64) -> Option<&T> {
+        self.get(x, y)
+    }
+}
+
+impl<T> Map2D<T> {
+    pub fn tiled(&self) -> Tiled<'_, T> {
+        Tiled::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid;
+
+    #[test]
+    fn wrapping() {
+        let map = grid("...\n.#.\n...\n");
+        let tiled = map.tiled();
+
+        assert_eq!(tiled.get(-1, -4), Some(&'.'));
+        assert_eq!(tiled.get(4, -2), Some(&'#'));
+        assert_eq!(tiled.local(-1, -4), Some((2, 2)));
+        assert_eq!(tiled.tile(-1, 3), Some((-1, 1)));
+        assert_eq!(tiled.tile(2, 2), Some((0, 0)));
+        assert_eq!(tiled.cell(-3, 7), Some(&'.'));
+    }
+
+    #[test]
+    fn empty_map() {
+        let map: Map2D<char> = Map2D::new(3, 0);
+        let tiled = map.tiled();
+
+        assert_eq!(tiled.get(0, 0), None);
+        assert_eq!(tiled.local(1, 1), None);
+        assert_eq!(tiled.tile(-1, 2), None);
+    }
+}
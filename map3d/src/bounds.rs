@@ -0,0 +1,72 @@
+use crate::Point3;
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub struct Bounds3 {
+    pub min: Point3,
+    pub max: Point3,
+}
+
+impl Bounds3 {
+    pub fn new(min: Point3, max: Point3) -> Self {
+        Self { min, max }
+    }
+    pub fn width(&self) -> usize {
+        (self.max.x - self.min.x).max(0) as usize
+    }
+    pub fn height(&self) -> usize {
+        (self.max.y - self.min.y).max(0) as usize
+    }
+    pub fn depth(&self) -> usize {
+        (self.max.z - self.min.z).max(0) as usize
+    }
+    pub fn volume(&self) -> usize {
+        self.width() * self.height() * self.depth()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.volume() == 0
+    }
+    pub fn contains(&self, point: Point3) -> bool {
+        (self.min.x..self.max.x).contains(&point.x)
+            && (self.min.y..self.max.y).contains(&point.y)
+            && (self.min.z..self.max.z).contains(&point.z)
+    }
+    pub fn intersects(&self, other: &Bounds3) -> bool {
+        self.min.x < other.max.x
+            && other.min.x < self.max.x
+            && self.min.y < other.max.y
+            && other.min.y < self.max.y
+            && self.min.z < other.max.z
+            && other.min.z < self.max.z
+    }
+    pub fn extend(&mut self, point: Point3) {
+        if self.is_empty() {
+            *self = Self::new(point, point + Point3::new(1, 1, 1));
+            return;
+        }
+        self.min = Point3::new(
+            self.min.x.min(point.x),
+            self.min.y.min(point.y),
+            self.min.z.min(point.z),
+        );
+        self.max = Point3::new(
+            self.max.x.max(point.x + 1),
+            self.max.y.max(point.y + 1),
+            self.max.z.max(point.z + 1),
+        );
+    }
+    pub fn points(self) -> impl Iterator<Item = Point3> {
+        (self.min.z..self.max.z).flat_map(move |z| {
+            (self.min.y..self.max.y)
+                .flat_map(move |y| (self.min.x..self.max.x).map(move |x| Point3::new(x, y, z)))
+        })
+    }
+}
+
+impl FromIterator<Point3> for Bounds3 {
+    fn from_iter<I: IntoIterator<Item = Point3>>(iter: I) -> Self {
+        iter.into_iter().fold(Self::default(), |mut bounds, point| {
+            bounds.extend(point);
+            bounds
+        })
+    }
+}
@@ -0,0 +1,222 @@
+mod bounds;
+mod point;
+
+pub use bounds::Bounds3;
+pub use point::Point3;
+
+use anyhow::{bail, Result};
+use map2d::Map2D;
+use std::collections::{HashSet, VecDeque};
+use std::ops::{Index, IndexMut};
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Map3D<T> {
+    inner: Box<[T]>,
+    depth: usize,
+    height: usize,
+    width: usize,
+}
+
+impl<T: Default> Map3D<T> {
+    pub fn new(depth: usize, height: usize, width: usize) -> Self {
+        Self::from_fn(depth, height, width, |_| T::default())
+    }
+}
+
+impl<T> Map3D<T> {
+    pub fn from_fn<F>(depth: usize, height: usize, width: usize, f: F) -> Self
+    where
+        F: FnMut(Point3) -> T,
+    {
+        let bounds = Bounds3::new(
+            Point3::default(),
+            Point3::new(width as i64, height as i64, depth as i64),
+        );
+        Self {
+            inner: bounds.points().map(f).collect(),
+            depth,
+            height,
+            width,
+        }
+    }
+    pub fn from_layers(layers: Vec<Map2D<T>>) -> Result<Self> {
+        let depth = layers.len();
+        let (height, width) = layers
+            .first()
+            .map_or((0, 0), |layer| (layer.height(), layer.width()));
+
+        if let Some(z) = layers
+            .iter()
+            .position(|layer| layer.height() != height || layer.width() != width)
+        {
+            bail!(
+                "incorrect layer size '{}x{}' at depth {z}, expected '{width}x{height}'",
+                layers[z].width(),
+                layers[z].height()
+            );
+        }
+
+        let inner = layers
+            .into_iter()
+            .flat_map(|layer| layer.into_rows())
+            .flat_map(|row| row.into_vec())
+            .collect();
+
+        Ok(Self {
+            inner,
+            depth,
+            height,
+            width,
+        })
+    }
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    pub fn bounds(&self) -> Bounds3 {
+        Bounds3::new(
+            Point3::default(),
+            Point3::new(self.width as i64, self.height as i64, self.depth as i64),
+        )
+    }
+    pub fn contains(&self, point: Point3) -> bool {
+        self.bounds().contains(point)
+    }
+    pub fn get(&self, point: Point3) -> Option<&T> {
+        let index = self.index_of(point)?;
+        Some(&self.inner[index])
+    }
+    pub fn get_mut(&mut self, point: Point3) -> Option<&mut T> {
+        let index = self.index_of(point)?;
+        Some(&mut self.inner[index])
+    }
+    pub fn iter_with_positions(&self) -> impl Iterator<Item = (Point3, &T)> {
+        self.bounds().points().zip(self.inner.iter())
+    }
+    pub fn bounding_box<P>(&self, mut predicate: P) -> Bounds3
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter_with_positions()
+            .filter(|(_, cell)| predicate(cell))
+            .map(|(point, _)| point)
+            .collect()
+    }
+    pub fn flood_fill<P>(&self, start: Point3, mut passable: P) -> HashSet<Point3>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let mut filled = HashSet::new();
+        if !self.get(start).is_some_and(&mut passable) {
+            return filled;
+        }
+
+        let mut queue = VecDeque::from([start]);
+        filled.insert(start);
+
+        while let Some(point) = queue.pop_front() {
+            for neighbor in point.neighbors6() {
+                if filled.contains(&neighbor) {
+                    continue;
+                }
+                if self.get(neighbor).is_some_and(&mut passable) {
+                    filled.insert(neighbor);
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+        filled
+    }
+    fn index_of(&self, point: Point3) -> Option<usize> {
+        if !self.contains(point) {
+            return None;
+        }
+        let (x, y, z) = (point.x as usize, point.y as usize, point.z as usize);
+        Some((z * self.height + y) * self.width + x)
+    }
+}
+
+impl<T: Clone> Map3D<T> {
+    pub fn layer(&self, z: usize) -> Option<Map2D<T>> {
+        if z >= self.depth {
+            return None;
+        }
+        let offset = z * self.height * self.width;
+        Some(Map2D::from_fn(self.height, self.width, |x, y| {
+            self.inner[offset + y * self.width + x].clone()
+        }))
+    }
+    pub fn layers(&self) -> impl Iterator<Item = Map2D<T>> + '_ {
+        (0..self.depth).filter_map(|z| self.layer(z))
+    }
+}
+
+impl<T> Index<Point3> for Map3D<T> {
+    type Output = T;
+
+    fn index(&self, point: Point3) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("point {point:?} out of bounds"))
+    }
+}
+
+impl<T> IndexMut<Point3> for Map3D<T> {
+    fn index_mut(&mut self, point: Point3) -> &mut T {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("point {point:?} out of bounds"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layers_round_trip() -> Result<()> {
+        let map = Map3D::from_fn(2, 2, 3, |point| point.x + 10 * point.y + 100 * point.z);
+
+        assert_eq!(map[Point3::new(2, 1, 1)], 112);
+        assert_eq!(
+            map.layer(1).and_then(|layer| layer.get(0, 1).copied()),
+            Some(110)
+        );
+        assert_eq!(Map3D::from_layers(map.layers().collect())?, map);
+
+        let mismatched = vec![Map2D::<u8>::new(1, 1), Map2D::new(1, 2)];
+        assert!(Map3D::from_layers(mismatched).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn neighbors() {
+        let origin = Point3::default();
+        assert_eq!(origin.neighbors6().count(), 6);
+        assert_eq!(origin.neighbors26().count(), 26);
+        assert!(origin.neighbors26().all(|point| point != origin));
+    }
+
+    #[test]
+    fn flood_fill_and_bounds() {
+        let mut map: Map3D<bool> = Map3D::new(3, 3, 3);
+        // Wall off the z = 1 plane except for the centre cell
+        for point in map.bounds().points().filter(|point| point.z == 1) {
+            map[point] = point != Point3::new(1, 1, 1);
+        }
+
+        assert_eq!(map.flood_fill(Point3::new(0, 0, 0), |wall| !wall).len(), 19);
+        map[Point3::new(1, 1, 1)] = true;
+        assert_eq!(map.flood_fill(Point3::new(0, 0, 0), |wall| !wall).len(), 9);
+
+        let walls = map.bounding_box(|wall| *wall);
+        assert_eq!(
+            walls,
+            Bounds3::new(Point3::new(0, 0, 1), Point3::new(3, 3, 2))
+        );
+        assert!(!walls.intersects(&Bounds3::new(Point3::new(0, 0, 2), Point3::new(1, 1, 3))));
+    }
+}
@@ -0,0 +1,99 @@
+use crate::UnionFind;
+use map2d::{Direction, Map2D};
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct GridUnionFind {
+    sets: UnionFind,
+    width: usize,
+}
+
+impl GridUnionFind {
+    pub fn new<T, F>(map: &Map2D<T>, mut connected: F) -> Self
+    where
+        F: FnMut(&T, &T, Direction) -> bool,
+    {
+        let width = map.width();
+        let mut sets = UnionFind::new(map.height() * width);
+
+        for ((x, y), cell) in map.iter_with_positions() {
+            if x + 1 < width && connected(cell, &map[y][x + 1], Direction::Right) {
+                sets.union(y * width + x, y * width + x + 1);
+            }
+            if y + 1 < map.height() && connected(cell, &map[y + 1][x], Direction::Down) {
+                sets.union(y * width + x, (y + 1) * width + x);
+            }
+        }
+        Self { sets, width }
+    }
+    pub fn regions<T: PartialEq>(map: &Map2D<T>) -> Self {
+        Self::new(map, |a, b, _| a == b)
+    }
+    pub fn find(&mut self, (x, y): (usize, usize)) -> (usize, usize) {
+        let root = self.sets.find(y * self.width + x);
+        self.position(root)
+    }
+    pub fn connected(&mut self, a: (usize, usize), b: (usize, usize)) -> bool {
+        self.find(a) == self.find(b)
+    }
+    pub fn size(&mut self, (x, y): (usize, usize)) -> usize {
+        self.sets.size(y * self.width + x)
+    }
+    pub fn components(&self) -> usize {
+        self.sets.components()
+    }
+    pub fn groups(&mut self) -> Vec<Vec<(usize, usize)>> {
+        let width = self.width;
+        self.sets
+            .groups()
+            .into_iter()
+            .map(|group| {
+                group
+                    .into_iter()
+                    .map(|index| (index % width, index / width))
+                    .collect()
+            })
+            .collect()
+    }
+    pub fn into_inner(self) -> UnionFind {
+        self.sets
+    }
+    fn position(&self, index: usize) -> (usize, usize) {
+        (index % self.width, index / self.width)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn regions() {
+        let map = Map2D::<char>::from_reader("aab\nbcb\nbbb\n".as_bytes()).unwrap();
+        let mut regions = GridUnionFind::regions(&map);
+
+        assert_eq!(regions.components(), 3);
+        assert!(regions.connected((2, 0), (0, 2)));
+        assert!(!regions.connected((0, 0), (1, 1)));
+        assert_eq!(regions.size((0, 1)), 6);
+        assert_eq!(
+            regions.groups(),
+            vec![
+                vec![(0, 0), (1, 0)],
+                vec![(2, 0), (0, 1), (2, 1), (0, 2), (1, 2), (2, 2)],
+                vec![(1, 1)]
+            ]
+        );
+    }
+
+    #[test]
+    fn directional() {
+        let map = Map2D::<char>::from_reader("-7\n.|\n".as_bytes()).unwrap();
+        let pipes = GridUnionFind::new(&map, |a, b, direction| match direction {
+            Direction::Right => "-LF".contains(*a) && "-J7".contains(*b),
+            Direction::Down => "|7F".contains(*a) && "|LJ".contains(*b),
+            _ => false,
+        });
+
+        assert_eq!(pipes.components(), 2);
+    }
+}
@@ -0,0 +1,123 @@
+mod grid;
+
+pub use grid::GridUnionFind;
+
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct UnionFind {
+    parents: Vec<usize>,
+    ranks: Vec<u8>,
+    sizes: Vec<usize>,
+    components: usize,
+}
+
+impl UnionFind {
+    pub fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+            ranks: vec![0; len],
+            sizes: vec![1; len],
+            components: len,
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+    pub fn push(&mut self) -> usize {
+        let index = self.parents.len();
+        self.parents.push(index);
+        self.ranks.push(0);
+        self.sizes.push(1);
+        self.components += 1;
+        index
+    }
+    pub fn find(&mut self, index: usize) -> usize {
+        let mut root = index;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        let mut current = index;
+        while self.parents[current] != root {
+            current = std::mem::replace(&mut self.parents[current], root);
+        }
+        root
+    }
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        let (root, child) = match self.ranks[a] < self.ranks[b] {
+            true => (b, a),
+            false => (a, b),
+        };
+        if self.ranks[root] == self.ranks[child] {
+            self.ranks[root] += 1;
+        }
+        self.parents[child] = root;
+        self.sizes[root] += self.sizes[child];
+        self.components -= 1;
+        true
+    }
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+    pub fn size(&mut self, index: usize) -> usize {
+        let root = self.find(index);
+        self.sizes[root]
+    }
+    pub fn components(&self) -> usize {
+        self.components
+    }
+    pub fn roots(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.len()).filter(|index| self.parents[*index] == *index)
+    }
+    pub fn component_sizes(&self) -> Vec<usize> {
+        self.roots().map(|root| self.sizes[root]).collect()
+    }
+    pub fn groups(&mut self) -> Vec<Vec<usize>> {
+        let mut groups: HashMap<usize, Vec<usize>> = HashMap::new();
+        for index in 0..self.len() {
+            let root = self.find(index);
+            groups.entry(root).or_default().push(index);
+        }
+
+        let mut groups: Vec<_> = groups.into_values().collect();
+        groups.sort_unstable();
+        groups
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn union_and_find() {
+        let mut sets = UnionFind::new(6);
+
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 3));
+        assert!(sets.union(1, 3));
+        assert!(!sets.union(0, 2));
+
+        assert!(sets.connected(0, 3));
+        assert!(!sets.connected(0, 4));
+        assert_eq!(sets.size(2), 4);
+        assert_eq!(sets.components(), 3);
+
+        let new = sets.push();
+        sets.union(new, 5);
+        assert_eq!(sets.groups(), vec![vec![0, 1, 2, 3], vec![4], vec![5, 6]]);
+
+        let mut sizes = sets.component_sizes();
+        sizes.sort_unstable();
+        assert_eq!(sizes, vec![1, 2, 4]);
+    }
+}